@@ -0,0 +1,39 @@
+/* Generated by `aoc header`, do not edit. */
+
+#ifndef AOC2021_H
+#define AOC2021_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The answer was found. */
+#define AOC_OK 0
+/* The input could not be parsed or solved. */
+#define AOC_ERROR 1
+/* No day has that number. */
+#define AOC_UNKNOWN_DAY 2
+/* The part is not 'a' or 'b', or the input is not UTF-8. */
+#define AOC_INVALID_ARGUMENT 3
+/* The solver crashed. */
+#define AOC_PANIC 4
+
+/* Solves one part ('a' or 'b') of a day on the `len` bytes of puzzle input at `input`.
+ * Returns AOC_OK with the answer in `*answer`, or another status with why it failed in
+ * `*answer`. Either string must be released with aoc_free. `input` may be NULL when
+ * `len` is 0.
+ */
+int32_t aoc_solve(uint32_t day, char part, const uint8_t *input, size_t len, char **answer);
+
+/* Releases a string returned by aoc_solve. Does nothing for NULL.
+ */
+void aoc_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC2021_H */
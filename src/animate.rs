@@ -0,0 +1,365 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use common::days::day13::{self, Day13};
+use common::days::day17::{self, Day17};
+use common::days::day4::{self, Bingo, Day4};
+use common::days::day6::{self, Day6};
+use common::geometry::{Point, Rect};
+use common::grid::Grid;
+use common::{load_file, AocError, Solution};
+
+use crate::{default_input, usage};
+
+/// The days with an animation.
+const ANIMATED: &[u32] = &[4, 6, 13, 17];
+
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+/// Drawings are shrunk to fit this many columns and rows.
+const MAX_SIZE: (u64, u64) = (100, 40);
+const FISH_DAYS: usize = 256;
+const BAR_WIDTH: usize = 60;
+const BOARDS_SHOWN: usize = 24;
+const BOARDS_PER_ROW: usize = 6;
+
+const HOME: &str = "\x1b[H";
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CLEAR_LINE: &str = "\x1b[K";
+const CLEAR_BELOW: &str = "\x1b[J";
+const INVERSE: &str = "\x1b[7m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// Draws frames over each other in the terminal, waiting between them.
+struct Screen {
+    delay: Duration,
+    max_frames: Option<usize>,
+    frames: usize,
+    /// Why a frame could not be written, after which no more are shown.
+    error: Option<io::Error>,
+}
+
+impl Screen {
+    /// Shows a frame, unless the maximum number of frames was shown already or writing one
+    /// failed.
+    fn show(&mut self, frame: &[String]) {
+        if self.error.is_some() || self.max_frames.is_some_and(|max| self.frames >= max) {
+            return;
+        }
+
+        let mut out = String::new();
+        if self.frames == 0 {
+            out.push_str(CLEAR_SCREEN);
+        }
+        out.push_str(HOME);
+        for line in frame {
+            out.push_str(line);
+            out.push_str(CLEAR_LINE);
+            out.push('\n');
+        }
+        out.push_str(CLEAR_BELOW);
+
+        let mut stdout = io::stdout().lock();
+        if let Err(err) = stdout
+            .write_all(out.as_bytes())
+            .and_then(|()| stdout.flush())
+        {
+            self.error = Some(err);
+            return;
+        }
+        self.frames += 1;
+
+        if !self.delay.is_zero() {
+            thread::sleep(self.delay);
+        }
+    }
+}
+
+/// Plays a day's simulation step by step in the terminal: day 4's boards being marked, day 6's
+/// fish by timer, day 13's folds and day 17's highest shot.
+pub fn animate(args: &[String]) {
+    let mut target = None;
+    let mut delay = DEFAULT_DELAY;
+    let mut max_frames = None;
+    let mut file = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--delay" => {
+                delay = match args.next().map(|s| s.parse::<u64>()) {
+                    Some(Ok(ms)) => Duration::from_millis(ms),
+                    _ => usage(),
+                }
+            }
+            "--frames" => {
+                max_frames = match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(frames)) if frames > 0 => Some(frames),
+                    _ => usage(),
+                }
+            }
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ if file.is_none() => file = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+
+    let number = match target.map(|number| number.parse::<u32>()) {
+        Some(Ok(number)) => number,
+        _ => usage(),
+    };
+    if !ANIMATED.contains(&number) {
+        println!("No animation for day {}, try day 4, 6, 13 or 17", number);
+        process::exit(1);
+    }
+    let file = file.unwrap_or_else(|| default_input(number));
+
+    let mut screen = Screen {
+        delay,
+        max_frames,
+        frames: 0,
+        error: None,
+    };
+    let played = play(number, &file, &mut screen);
+
+    match screen.error {
+        // whatever read the frames stopped, as `head` does
+        Some(err) if err.kind() == io::ErrorKind::BrokenPipe => {}
+        Some(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+        None => {}
+    }
+    if let Err(err) = played {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn play(number: u32, file: &str, screen: &mut Screen) -> Result<(), AocError> {
+    let lines = load_file(file)?;
+    let in_file = |e: AocError| e.in_file(file);
+
+    match number {
+        4 => {
+            let bingo = Day4::parse(&lines).map_err(in_file)?;
+            day4::find_last_board_score_observed(&bingo.numbers, &bingo.boards, |drawn, won| {
+                screen.show(&bingo_frame(&bingo, drawn, won))
+            });
+        }
+        6 => {
+            let fish = Day6::parse(&lines).map_err(in_file)?;
+            day6::simulate_observed(fish, FISH_DAYS, |day, per_day| {
+                screen.show(&fish_frame(day, per_day))
+            });
+        }
+        13 => {
+            let manual = Day13::parse(&lines).map_err(in_file)?;
+            let stages = day13::fold_stages(&manual.positions, &manual.folds);
+            for (folded, sheet) in stages.iter().enumerate() {
+                let header = match folded {
+                    0 => format!("{} dots before folding", sheet.dots.len()),
+                    _ => format!(
+                        "fold {} of {} along {}: {} dots",
+                        folded,
+                        manual.folds.len(),
+                        manual.folds[folded - 1],
+                        sheet.dots.len()
+                    ),
+                };
+                let marks: Vec<(Point, char)> = sheet.dots.iter().map(|(p, _)| (p, '#')).collect();
+                let mut frame = vec![header];
+                if let Some(paper) = sheet.area() {
+                    frame.extend(fit(&marks, paper, '.', false));
+                }
+                screen.show(&frame);
+            }
+        }
+        17 => {
+            let target = Day17::parse(&lines).map_err(in_file)?;
+            let highest = day17::hits(Point::ORIGIN, target).max_by_key(|&(_, max_y)| max_y);
+            let velocity = match highest {
+                Some((velocity, _)) => velocity,
+                None => return Err(AocError::new("no shot hits the target").in_file(file)),
+            };
+
+            let path = day17::trajectory(Point::ORIGIN, velocity, &target);
+            let area = path
+                .iter()
+                .fold(target.including(Point::ORIGIN), |area, &p| {
+                    area.including(p)
+                });
+            let mut marks: Vec<(Point, char)> = target.points().map(|p| (p, '#')).collect();
+
+            day17::trace_observed(Point::ORIGIN, velocity, &target, |position, velocity| {
+                marks.push((position, 'o'));
+                let mut frame = vec![format!(
+                    "probe at {},{} moving {},{}",
+                    position.x, position.y, velocity.dx, velocity.dy
+                )];
+                frame.extend(fit(&marks, area, ' ', true));
+                screen.show(&frame);
+                marks.last_mut().unwrap().1 = '.';
+            });
+        }
+        _ => unreachable!("day {} is not animated", number),
+    }
+
+    Ok(())
+}
+
+/// Draws marks within `area` in at most `MAX_SIZE` columns and rows, each character standing
+/// for a block of points. Where marks share a block the later one shows. With `up`, the
+/// highest `y` is at the top.
+fn fit(marks: &[(Point, char)], area: Rect, background: char, up: bool) -> Vec<String> {
+    let block_width = area.width().div_ceil(MAX_SIZE.0);
+    let block_height = area.height().div_ceil(MAX_SIZE.1);
+    let columns = area.width().div_ceil(block_width) as usize;
+    let rows = area.height().div_ceil(block_height) as usize;
+
+    let mut cells = vec![vec![background; columns]; rows];
+    for &(p, mark) in marks.iter().filter(|(p, _)| area.contains(*p)) {
+        let column = (p.x - area.min.x) as u64 / block_width;
+        let row = if up {
+            (area.max.y - p.y) as u64 / block_height
+        } else {
+            (p.y - area.min.y) as u64 / block_height
+        };
+        cells[row as usize][column as usize] = mark;
+    }
+
+    cells
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
+
+/// A histogram of the fish by timer value.
+fn fish_frame(day: usize, per_day: &HashMap<u8, usize>) -> Vec<String> {
+    let total: usize = per_day.values().sum();
+    let most = per_day.values().copied().max().unwrap_or(0).max(1);
+
+    let mut frame = vec![format!("day {}: {} fish", day, total)];
+    frame.extend((0..=8).map(|timer| {
+        let fish = per_day.get(&timer).copied().unwrap_or(0);
+        let bar = "#".repeat(fish * BAR_WIDTH / most);
+        format!("{} {:<width$} {}", timer, bar, fish, width = BAR_WIDTH)
+    }));
+    frame
+}
+
+/// The boards with the numbers drawn so far marked, and those that won in green.
+fn bingo_frame(bingo: &Bingo, drawn: usize, won: &[usize]) -> Vec<String> {
+    let marked = &bingo.numbers[..drawn];
+    let mut frame = vec![format!(
+        "drew {} ({} of {}), {} of {} boards won",
+        marked.last().map_or(String::new(), |n| n.to_string()),
+        drawn,
+        bingo.numbers.len(),
+        won.len(),
+        bingo.boards.len()
+    )];
+
+    let shown: Vec<usize> = (0..bingo.boards.len().min(BOARDS_SHOWN)).collect();
+    for row in shown.chunks(BOARDS_PER_ROW) {
+        frame.push(String::new());
+        frame.push(
+            row.iter()
+                .map(|&id| match won.iter().position(|&w| w == id) {
+                    Some(place) => format!("#{:<3} won {:<6}", id + 1, ordinal(place + 1)),
+                    None => format!("#{:<14}", id + 1),
+                })
+                .collect::<Vec<String>>()
+                .join("   "),
+        );
+
+        let height = bingo.boards[row[0]].lines.len();
+        for line in 0..height {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|&id| {
+                    let colour = if won.contains(&id) { GREEN } else { "" };
+                    bingo.boards[id].lines[line]
+                        .iter()
+                        .map(|n| {
+                            let inverse = if marked.contains(n) { INVERSE } else { "" };
+                            format!("{}{}{:>2}{}", colour, inverse, n, RESET)
+                        })
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                })
+                .collect();
+            frame.push(cells.join("   "));
+        }
+    }
+
+    if bingo.boards.len() > BOARDS_SHOWN {
+        frame.push(String::new());
+        frame.push(format!(
+            "and {} more boards",
+            bingo.boards.len() - BOARDS_SHOWN
+        ));
+    }
+    frame
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit() {
+        let marks = [(Point::new(0, 0), '#'), (Point::new(2, 1), '#')];
+        let area = Rect::new(Point::ORIGIN, Point::new(2, 1));
+
+        assert_eq!(fit(&marks, area, '.', false), ["#..", "..#"]);
+        assert_eq!(fit(&marks, area, '.', true), ["..#", "#.."]);
+
+        // blocks of 3x2 points when shrinking 300 columns and 80 rows
+        let area = Rect::new(Point::ORIGIN, Point::new(299, 79));
+        let marks = [(Point::new(5, 3), 'a'), (Point::new(4, 2), 'b')];
+        let shrunk = fit(&marks, area, ' ', false);
+        assert_eq!((shrunk.len(), shrunk[0].len()), (40, 100));
+        assert_eq!(shrunk[1].trim(), "b");
+    }
+
+    #[test]
+    fn test_fish_frame() {
+        let per_day = HashMap::from([(0, 2), (6, 4)]);
+        let frame = fish_frame(3, &per_day);
+
+        assert_eq!(frame.len(), 10);
+        assert_eq!(frame[0], "day 3: 6 fish");
+        let fields = |line: &str| {
+            line.split_whitespace()
+                .map(|s| s.to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(fields(&frame[1]), ["0", &"#".repeat(30), "2"]);
+        assert_eq!(fields(&frame[2]), ["1", "0"]);
+        assert_eq!(fields(&frame[7]), ["6", &"#".repeat(60), "4"]);
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(12), "12th");
+        assert_eq!(ordinal(23), "23rd");
+    }
+}
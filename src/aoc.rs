@@ -0,0 +1,280 @@
+use std::env;
+use std::process;
+
+use common::bench::{AllocationCounts, Timings};
+use common::days::{find_day, Day, DAYS};
+use common::manifest::escape_answer;
+use common::{json, load_file, load_file_strict, Answer, Answers, AocError, Part, STDIN};
+
+mod animate;
+mod batch;
+mod benchmark;
+mod differential;
+mod generate;
+mod images;
+mod repl;
+mod scaffold;
+mod summary;
+mod verify;
+mod watch;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: common::alloc::CountingAllocator = common::alloc::CountingAllocator;
+
+const USAGE: &str = "usage:
+    aoc run <day> [--part a|b] [--format text|json|tsv] [--strict] [<file>|-]
+    aoc run all [--part a|b] [--format text|json|tsv] [--strict]
+    aoc batch <day>|all [--part a|b] [--format text|csv|json] [<dir>]
+    aoc summary [--part a|b]
+    aoc verify [<manifest>]
+    aoc bench <day>|all [--runs N] [--json] [<file>]
+    aoc generate <day> [--seed N] [--size N]
+    aoc differential <day>|all [--runs N] [--size N]
+    aoc watch <day> [--part a|b] [--interval MS] [<file>...]
+    aoc repl
+    aoc animate <day> [--delay MS] [--frames N] [<file>]
+    aoc image <day> [--out DIR] [--scale N] [<file>]
+    aoc new <day>
+    aoc header";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("batch") => batch::batch(&args[1..]),
+        Some("summary") => summary::summary(&args[1..]),
+        Some("verify") => verify::verify(&args[1..]),
+        Some("bench") => benchmark::benchmark(&args[1..]),
+        Some("generate") => generate::generate(&args[1..]),
+        Some("differential") => differential::differential(&args[1..]),
+        Some("watch") => watch::watch(&args[1..]),
+        Some("animate") => animate::animate(&args[1..]),
+        Some("image") => images::images(&args[1..]),
+        Some("repl") => repl::repl(&args[1..]),
+        Some("new") => scaffold::new_day(&args[1..]),
+        Some("header") if args.len() == 1 => print!("{}", common::ffi::header()),
+        _ => usage(),
+    }
+}
+
+pub fn usage() -> ! {
+    println!("{}", USAGE);
+    process::exit(1);
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+fn run(args: &[String]) {
+    let mut target = None;
+    let mut parts = vec![Part::A, Part::B];
+    let mut format = Format::Text;
+    let mut strict = false;
+    let mut file = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                parts = match args.next().map(|s| s.parse::<Part>()) {
+                    Some(Ok(part)) => vec![part],
+                    _ => usage(),
+                }
+            }
+            "--format" => {
+                format = match args.next().map(|s| s.as_str()) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("tsv") => Format::Tsv,
+                    _ => usage(),
+                }
+            }
+            "--strict" => strict = true,
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ if file.is_none() => file = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+
+    let selected: Vec<(&Day, String)> = match target {
+        Some("all") if file.is_none() => DAYS
+            .iter()
+            .map(|day| (day, default_input(day.number)))
+            .collect(),
+        Some(number) => match number.parse::<u32>().ok().and_then(find_day) {
+            Some(day) => vec![(day, file.unwrap_or_else(|| default_input(day.number)))],
+            None => {
+                println!("No solution registered for day {}", number);
+                process::exit(1);
+            }
+        },
+        None => usage(),
+    };
+
+    let results: Vec<Solved> = selected
+        .into_iter()
+        .map(|(day, input)| {
+            let answers = solve_file(day, &input, &parts, strict);
+            (day.number, input, answers)
+        })
+        .collect();
+
+    match format {
+        Format::Text => print_text(&results),
+        Format::Json => println!("{}", to_json(&results)),
+        Format::Tsv => print!("{}", to_tsv(&results)),
+    }
+
+    if any_failed(&results) {
+        process::exit(1);
+    }
+}
+
+/// The answers for one day on one input, or why they could not be found.
+pub type Solved = (u32, String, Result<Answers, AocError>);
+
+/// Whether an input failed to parse or any part failed to find its answer.
+pub fn any_failed(results: &[Solved]) -> bool {
+    results.iter().any(|(_, _, answers)| match answers {
+        Ok(answers) => answers.iter().any(|(_, answer)| answer.is_err()),
+        Err(_) => true,
+    })
+}
+
+fn print_text(results: &[Solved]) {
+    for (number, _, answers) in results {
+        match answers {
+            Ok(answers) => {
+                for (part, answer) in answers {
+                    match answer {
+                        Ok(answer @ Answer::Grid(_)) => {
+                            println!("Day {} Part {}:\n{}", number, part, answer)
+                        }
+                        Ok(answer) => println!("Day {} Part {}: {}", number, part, answer),
+                        Err(err) => eprintln!("Day {} Part {}: {}", number, part, err),
+                    }
+                }
+            }
+            Err(err) => eprintln!("{}", err),
+        }
+    }
+}
+
+pub fn to_json(results: &[Solved]) -> String {
+    let mut entries = Vec::new();
+
+    for (number, input, answers) in results {
+        match answers {
+            Ok(answers) => {
+                for (part, answer) in answers {
+                    entries.push(match answer {
+                        Ok(answer) => format!(
+                            "{{\"day\":{},\"part\":\"{}\",\"input\":{},\"type\":\"{}\",\"answer\":{}}}",
+                            number,
+                            part,
+                            json::string(input),
+                            answer.kind(),
+                            answer.to_json()
+                        ),
+                        Err(err) => format!(
+                            "{{\"day\":{},\"part\":\"{}\",\"input\":{},\"error\":{}}}",
+                            number,
+                            part,
+                            json::string(input),
+                            json::string(&err.to_string())
+                        ),
+                    });
+                }
+            }
+            Err(err) => entries.push(format!(
+                "{{\"day\":{},\"input\":{},\"error\":{}}}",
+                number,
+                json::string(input),
+                json::string(&err.to_string())
+            )),
+        }
+    }
+
+    format!("[{}]", entries.join(","))
+}
+
+fn to_tsv(results: &[Solved]) -> String {
+    let mut tsv = String::from("day\tpart\tinput\ttype\tanswer\n");
+
+    for (number, input, answers) in results {
+        match answers {
+            Ok(answers) => {
+                for (part, answer) in answers {
+                    let (kind, answer) = match answer {
+                        Ok(answer) => (answer.kind(), answer.to_string()),
+                        Err(err) => ("error", err.to_string()),
+                    };
+                    tsv.push_str(&format!(
+                        "{}\t{}\t{}\t{}\t{}\n",
+                        number,
+                        part,
+                        input,
+                        kind,
+                        escape_answer(&answer)
+                    ));
+                }
+            }
+            Err(err) => tsv.push_str(&format!(
+                "{}\t\t{}\terror\t{}\n",
+                number,
+                input,
+                escape_answer(&err.to_string())
+            )),
+        }
+    }
+
+    tsv
+}
+
+pub fn default_input(number: u32) -> String {
+    format!("data/day{}.txt", number)
+}
+
+/// Solves a day on a file, rejecting untidy input instead of normalising it when `strict`.
+pub fn solve_file(
+    day: &Day,
+    file: &str,
+    parts: &[Part],
+    strict: bool,
+) -> Result<Answers, AocError> {
+    let lines = if strict {
+        load_file_strict(file)?
+    } else {
+        load_file(file)?
+    };
+    let name = if file == "-" { STDIN } else { file };
+    let answers = (day.solve)(&lines, parts).map_err(|e| e.in_file(name))?;
+    Ok(answers
+        .into_iter()
+        .map(|(part, answer)| (part, answer.map_err(|e| e.in_file(name))))
+        .collect())
+}
+
+/// Times a day on a file and, when `count` is set, counts what each phase allocates too.
+pub fn bench_file(
+    day: &Day,
+    file: &str,
+    runs: usize,
+    count: bool,
+) -> Result<(Timings, Option<AllocationCounts>), AocError> {
+    let lines = load_file(file)?;
+    let name = if file == "-" { STDIN } else { file };
+    let timings = (day.bench)(&lines, runs).map_err(|e| e.in_file(name))?;
+    let allocations = if count {
+        Some((day.count_allocations)(&lines).map_err(|e| e.in_file(name))?)
+    } else {
+        None
+    };
+    Ok((timings, allocations))
+}
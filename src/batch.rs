@@ -0,0 +1,298 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process;
+
+use common::manifest::escape_answer;
+use common::Part;
+
+use crate::watch::example_inputs;
+use crate::{any_failed, default_input, find_day, solve_file, to_json, usage, Day, Solved, DAYS};
+
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+/// Solves a day on every input in its directory, `data/dayN/` unless another is given, and
+/// prints one row per input and part so answers can be compared across accounts. Without such a
+/// directory it solves the day's puzzle input and examples.
+pub fn batch(args: &[String]) {
+    let mut target = None;
+    let mut parts = vec![Part::A, Part::B];
+    let mut format = Format::Text;
+    let mut dir = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                parts = match args.next().map(|s| s.parse::<Part>()) {
+                    Some(Ok(part)) => vec![part],
+                    _ => usage(),
+                }
+            }
+            "--format" => {
+                format = match args.next().map(|s| s.as_str()) {
+                    Some("text") => Format::Text,
+                    Some("csv") => Format::Csv,
+                    Some("json") => Format::Json,
+                    _ => usage(),
+                }
+            }
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ if dir.is_none() => dir = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+
+    let selected: Vec<(&Day, Vec<String>)> = match target {
+        // one day's inputs going missing should not stop the others from being checked
+        Some("all") if dir.is_none() => DAYS
+            .iter()
+            .filter_map(|day| match day_inputs(day.number) {
+                Ok(inputs) if !inputs.is_empty() => Some((day, inputs)),
+                Ok(_) => None,
+                Err(err) => {
+                    eprintln!("{}, skipping day {}", err, day.number);
+                    None
+                }
+            })
+            .collect(),
+        Some(number) => match number.parse::<u32>().ok().and_then(find_day) {
+            Some(day) => {
+                let inputs = match dir {
+                    Some(dir) => inputs_in(&dir),
+                    None => day_inputs(day.number),
+                };
+                match inputs {
+                    Ok(inputs) if !inputs.is_empty() => vec![(day, inputs)],
+                    Ok(_) => {
+                        eprintln!("day {}: no inputs", day.number);
+                        process::exit(1);
+                    }
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        process::exit(1);
+                    }
+                }
+            }
+            None => {
+                println!("No solution registered for day {}", number);
+                process::exit(1);
+            }
+        },
+        None => usage(),
+    };
+
+    if selected.is_empty() {
+        eprintln!("no day has any inputs, such as data/day1.txt or data/day1/");
+        process::exit(1);
+    }
+
+    let mut results: Vec<Solved> = Vec::new();
+    for (day, inputs) in selected {
+        results.extend(inputs.into_iter().map(|input| {
+            let answers = solve_file(day, &input, &parts, false);
+            (day.number, input, answers)
+        }));
+    }
+
+    match format {
+        Format::Text => print_table(&results),
+        Format::Csv => print!("{}", to_csv(&results)),
+        Format::Json => println!("{}", to_json(&results)),
+    }
+
+    if any_failed(&results) {
+        process::exit(1);
+    }
+}
+
+/// Where a day's named inputs are kept, one file per account or example.
+pub fn input_dir(number: u32) -> String {
+    format!("data/day{}", number)
+}
+
+/// A day's inputs in `data/dayN/` or, without that directory, its puzzle input if there is one
+/// and its examples.
+fn day_inputs(number: u32) -> Result<Vec<String>, String> {
+    let dir = input_dir(number);
+    if Path::new(&dir).is_dir() {
+        return inputs_in(&dir);
+    }
+
+    let input = default_input(number);
+    let mut inputs = Vec::new();
+    if Path::new(&input).is_file() {
+        inputs.push(input);
+    }
+    inputs.extend(example_inputs(number));
+    Ok(inputs)
+}
+
+/// The inputs in `dir`, or why there are none to run.
+fn inputs_in(dir: &str) -> Result<Vec<String>, String> {
+    match list_inputs(dir) {
+        Ok(inputs) if !inputs.is_empty() => Ok(inputs),
+        Ok(_) => Err(format!("{}: no inputs", dir)),
+        Err(err) => Err(format!("{}: {}", dir, err)),
+    }
+}
+
+/// The files in `dir` in name order, leaving out hidden ones such as editor swap files.
+pub fn list_inputs(dir: &str) -> io::Result<Vec<String>> {
+    let mut inputs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if entry.file_type()?.is_file() && !hidden {
+            inputs.push(entry.path().to_string_lossy().into_owned());
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+fn print_table(results: &[Solved]) {
+    let input_width = results
+        .iter()
+        .map(|(_, input, _)| input.len())
+        .chain(["input".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:>3}  part  {:<width$}  answer",
+        "day",
+        "input",
+        width = input_width
+    );
+    for (number, input, answers) in results {
+        match answers {
+            Ok(answers) => {
+                for (part, answer) in answers {
+                    let answer = match answer {
+                        Ok(answer) => escape_answer(&answer.to_string()),
+                        Err(err) => format!("ERROR {}", err),
+                    };
+                    println!(
+                        "{:>3}  {:<4}  {:<width$}  {}",
+                        number,
+                        part,
+                        input,
+                        answer,
+                        width = input_width
+                    );
+                }
+            }
+            Err(err) => println!(
+                "{:>3}  {:<4}  {:<width$}  ERROR {}",
+                number,
+                "",
+                input,
+                err,
+                width = input_width
+            ),
+        }
+    }
+}
+
+fn to_csv(results: &[Solved]) -> String {
+    let mut csv = String::from("day,part,input,answer,error\n");
+
+    for (number, input, answers) in results {
+        match answers {
+            Ok(answers) => {
+                for (part, answer) in answers {
+                    let (answer, error) = match answer {
+                        Ok(answer) => (answer.to_string(), String::new()),
+                        Err(err) => (String::new(), err.to_string()),
+                    };
+                    csv.push_str(&format!(
+                        "{},{},{},{},{}\n",
+                        number,
+                        part,
+                        csv_field(input),
+                        csv_field(&answer),
+                        csv_field(&error)
+                    ));
+                }
+            }
+            Err(err) => csv.push_str(&format!(
+                "{},,{},,{}\n",
+                number,
+                csv_field(input),
+                csv_field(&err.to_string())
+            )),
+        }
+    }
+
+    csv
+}
+
+/// Quotes a field that holds a comma, quote or line break, doubling its quotes.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Answer, AocError};
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("5934"), "5934");
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("#.\n.#"), "\"#.\n.#\"");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let results: Vec<Solved> = vec![
+            (
+                6,
+                "data/day6/alice.txt".to_owned(),
+                Ok(vec![
+                    (Part::A, Ok(Answer::Integer(5934))),
+                    (Part::B, Ok(Answer::Integer(26984457539))),
+                ]),
+            ),
+            (
+                13,
+                "data/day13/example.txt".to_owned(),
+                Ok(vec![
+                    (Part::A, Ok(Answer::Integer(17))),
+                    (
+                        Part::B,
+                        Err(AocError::new("expected letters 6 rows tall, found 7 rows")),
+                    ),
+                ]),
+            ),
+            (
+                6,
+                "data/day6/bob.txt".to_owned(),
+                Err(AocError::new("expected a timer, found \"x\"").at_line(1)),
+            ),
+        ];
+
+        assert_eq!(
+            to_csv(&results),
+            "day,part,input,answer,error
+6,A,data/day6/alice.txt,5934,
+6,B,data/day6/alice.txt,26984457539,
+13,A,data/day13/example.txt,17,
+13,B,data/day13/example.txt,,\"expected letters 6 rows tall, found 7 rows\"
+6,,data/day6/bob.txt,,\"1: expected a timer, found \"\"x\"\"\"
+"
+        );
+    }
+}
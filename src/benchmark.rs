@@ -0,0 +1,163 @@
+use std::process;
+use std::time::Duration;
+
+use common::alloc::{self, Allocations};
+use common::bench::{Phase, Summary};
+use common::json;
+
+use crate::{bench_file, default_input, find_day, usage, DAYS};
+
+const DEFAULT_RUNS: usize = 10;
+
+struct Measurement {
+    day: u32,
+    input: String,
+    phase: Phase,
+    summary: Summary,
+    allocations: Option<Allocations>,
+}
+
+/// Times every phase of the selected days and prints a table, or JSON with `--json`. Built with
+/// the `count-allocations` feature, it also reports what each phase allocates.
+pub fn benchmark(args: &[String]) {
+    let mut target = None;
+    let mut runs = DEFAULT_RUNS;
+    let mut json = false;
+    let mut file = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                runs = match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(runs)) if runs > 0 => runs,
+                    _ => usage(),
+                }
+            }
+            "--json" => json = true,
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ if file.is_none() => file = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+
+    let selected: Vec<(u32, String)> = match target {
+        Some("all") if file.is_none() => DAYS
+            .iter()
+            .map(|day| (day.number, default_input(day.number)))
+            .collect(),
+        Some(number) => match number.parse::<u32>().ok().and_then(find_day) {
+            Some(day) => vec![(
+                day.number,
+                file.unwrap_or_else(|| default_input(day.number)),
+            )],
+            None => {
+                println!("No solution registered for day {}", number);
+                process::exit(1);
+            }
+        },
+        None => usage(),
+    };
+
+    let counting = alloc::is_counting();
+    let mut results = Vec::new();
+    let mut failed = false;
+
+    for (number, input) in selected {
+        let day = find_day(number).unwrap();
+        match bench_file(day, &input, runs, counting) {
+            Ok((timings, allocations)) => {
+                for (phase, summary) in timings {
+                    let allocations = allocations.as_ref().and_then(|counts| {
+                        counts
+                            .iter()
+                            .find(|&&(counted, _)| counted == phase)
+                            .map(|&(_, allocations)| allocations)
+                    });
+                    results.push(Measurement {
+                        day: number,
+                        input: input.clone(),
+                        phase,
+                        summary,
+                        allocations,
+                    });
+                }
+            }
+            Err(err) => {
+                eprintln!("Day {}: {}", number, err);
+                failed = true;
+            }
+        }
+    }
+
+    if json {
+        println!("{}", to_json(&results));
+    } else {
+        print_table(&results);
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn print_table(results: &[Measurement]) {
+    let counted = results.iter().any(|r| r.allocations.is_some());
+
+    print!(
+        "{:>3}  {:<6}  {:>5}  {:>12}  {:>12}  {:>12}",
+        "day", "phase", "runs", "min", "median", "p95"
+    );
+    if counted {
+        print!("  {:>9}  {:>12}  {:>12}", "allocs", "bytes", "peak");
+    }
+    println!();
+
+    for r in results {
+        print!(
+            "{:>3}  {:<6}  {:>5}  {:>12}  {:>12}  {:>12}",
+            r.day,
+            r.phase,
+            r.summary.runs,
+            format_duration(r.summary.min),
+            format_duration(r.summary.median),
+            format_duration(r.summary.p95)
+        );
+        if let Some(a) = r.allocations {
+            print!("  {:>9}  {:>12}  {:>12}", a.count, a.bytes, a.peak);
+        }
+        println!();
+    }
+}
+
+fn format_duration(d: Duration) -> String {
+    format!("{:.3?}", d)
+}
+
+fn to_json(results: &[Measurement]) -> String {
+    let entries: Vec<String> = results
+        .iter()
+        .map(|r| {
+            let allocations = match r.allocations {
+                Some(a) => format!(
+                    ",\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}",
+                    a.count, a.bytes, a.peak
+                ),
+                None => String::new(),
+            };
+            format!(
+                "{{\"day\":{},\"input\":{},\"phase\":\"{}\",\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"p95_ns\":{}{}}}",
+                r.day,
+                json::string(&r.input),
+                r.phase,
+                r.summary.runs,
+                r.summary.min.as_nanos(),
+                r.summary.median.as_nanos(),
+                r.summary.p95.as_nanos(),
+                allocations
+            )
+        })
+        .collect();
+
+    format!("[{}]", entries.join(","))
+}
@@ -0,0 +1,132 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting every allocation it makes. It only counts once installed with
+/// `#[global_allocator]`, which the `aoc` binary does with the `count-allocations` feature.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn allocated(size: usize) {
+        let size = size as u64;
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn freed(size: usize) {
+        LIVE.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            CountingAllocator::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            CountingAllocator::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CountingAllocator::freed(layout.size());
+    }
+
+    /// Counted as a new allocation of `new_size` bytes replacing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CountingAllocator::freed(layout.size());
+            CountingAllocator::allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// What the code under `measure` allocated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    /// The most bytes live at once, above what was live when measuring started.
+    pub peak: u64,
+}
+
+/// Whether the counting allocator is installed, so `measure` reports anything.
+pub fn is_counting() -> bool {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(black_box(Box::new(0u8)));
+    ALLOCATIONS.load(Ordering::Relaxed) != before
+}
+
+/// Runs `f` and counts what it allocates, including anything it returns. The counters are
+/// shared by every thread, so measurements are only exact while nothing else runs.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let count = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = f();
+
+    let allocations = Allocations {
+        count: ALLOCATIONS.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, allocations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    // other tests allocate on their own threads at the same time, so only lower bounds hold
+
+    #[test]
+    fn test_measure() {
+        assert!(is_counting());
+
+        let (sum, allocations) = measure(|| {
+            let numbers: Vec<u64> = (0..1000).collect();
+            let copy = black_box(numbers.clone());
+            copy.iter().sum::<u64>()
+        });
+
+        assert_eq!(sum, 499500);
+        assert!(allocations.count >= 2);
+        assert!(allocations.bytes >= 16000);
+        assert!(allocations.peak >= 16000);
+    }
+
+    #[test]
+    fn test_realloc() {
+        let (numbers, allocations) = measure(|| {
+            let mut numbers = Vec::with_capacity(1);
+            numbers.extend(0..1000u64);
+            numbers
+        });
+
+        assert_eq!(numbers.len(), 1000);
+        assert!(allocations.count >= 2);
+        assert!(allocations.peak >= 8000);
+    }
+}
@@ -0,0 +1,125 @@
+use std::fmt::{self, Display};
+
+use crate::{json, AocError};
+
+/// A puzzle answer, keeping its shape so it can be printed for people or for scripts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of a picture, such as dots left on transparent paper.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::Text(_) => "string",
+            Answer::Grid(_) => "grid",
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Integer(n) => n.to_string(),
+            Answer::Text(s) => json::string(s),
+            Answer::Grid(rows) => {
+                let rows: Vec<String> = rows.iter().map(|row| json::string(row)).collect();
+                format!("[{}]", rows.join(","))
+            }
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_owned())
+    }
+}
+
+/// What a part may return: anything that converts into an `Answer`, or a `Result` of one for
+/// parts that can still fail after parsing, such as reading letters off a picture.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, AocError>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, AocError> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<T, AocError> {
+    fn into_answer(self) -> Result<Answer, AocError> {
+        self.map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(1574445493136u64).to_string(), "1574445493136");
+        assert_eq!(Answer::from("RZKZLPGH").to_string(), "RZKZLPGH");
+        assert_eq!(
+            Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()]).to_string(),
+            "#.\n.#"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(Answer::from(-3i64).to_json(), "-3");
+        assert_eq!(Answer::from("a\"b").to_json(), "\"a\\\"b\"");
+        assert_eq!(
+            Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()]).to_json(),
+            "[\"#.\",\".#\"]"
+        );
+    }
+
+    #[test]
+    fn test_into_answer() {
+        assert_eq!(7usize.into_answer(), Ok(Answer::Integer(7)));
+        assert_eq!(
+            Ok::<_, AocError>("RZKZLPGH").into_answer(),
+            Ok(Answer::from("RZKZLPGH"))
+        );
+        assert_eq!(
+            Err::<u64, _>(AocError::new("unreadable")).into_answer(),
+            Err(AocError::new("unreadable"))
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::alloc::{measure, Allocations};
+use crate::{Answer, AocError, IntoAnswer, Part, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    PartA,
+    PartB,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::PartA => f.pad("part_a"),
+            Phase::PartB => f.pad("part_b"),
+        }
+    }
+}
+
+/// Wall time statistics over repeated runs of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Summary {
+    pub fn from_samples(samples: &mut [Duration]) -> Summary {
+        samples.sort_unstable();
+
+        Summary {
+            runs: samples.len(),
+            min: percentile(samples, 0),
+            median: percentile(samples, 50),
+            p95: percentile(samples, 95),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+pub type Timings = Vec<(Phase, Summary)>;
+
+/// Times parsing and both parts of a day `runs` times each.
+pub fn bench<S: Solution>(lines: &[String], runs: usize) -> Result<Timings, AocError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part_a = Vec::with_capacity(runs);
+    let mut part_b = Vec::with_capacity(runs);
+
+    let input = S::parse(lines)?;
+
+    for _ in 0..runs {
+        parse.push(time(|| S::parse(black_box(lines))));
+        part_a.push(time(|| S::part_a(black_box(&input))));
+        part_b.push(time(|| S::part_b(black_box(&input))));
+    }
+
+    Ok(vec![
+        (Phase::Parse, Summary::from_samples(&mut parse)),
+        (Phase::PartA, Summary::from_samples(&mut part_a)),
+        (Phase::PartB, Summary::from_samples(&mut part_b)),
+    ])
+}
+
+pub type AllocationCounts = Vec<(Phase, Allocations)>;
+
+/// Counts what parsing and each part of a day allocate in one run. Only counts anything with
+/// the counting allocator installed, see `alloc::is_counting`.
+pub fn count_allocations<S: Solution>(lines: &[String]) -> Result<AllocationCounts, AocError> {
+    let (input, parse) = measure(|| S::parse(black_box(lines)));
+    let input = input?;
+    let (_, part_a) = measure(|| black_box(S::part_a(black_box(&input))));
+    let (_, part_b) = measure(|| black_box(S::part_b(black_box(&input))));
+
+    Ok(vec![
+        (Phase::Parse, parse),
+        (Phase::PartA, part_a),
+        (Phase::PartB, part_b),
+    ])
+}
+
+/// One part's answer, or why it could not be found, and how long the part took.
+pub type TimedAnswer = (Part, Result<Answer, AocError>, Duration);
+
+pub type TimedAnswers = Vec<TimedAnswer>;
+
+/// Parses `lines` once and solves the requested parts, timing each part on its own.
+pub fn solve_timed<S: Solution>(
+    lines: &[String],
+    parts: &[Part],
+) -> Result<TimedAnswers, AocError> {
+    let input = S::parse(lines)?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::A => S::part_a(&input).into_answer(),
+                Part::B => S::part_b(&input).into_answer(),
+            };
+            (part, answer, start.elapsed())
+        })
+        .collect())
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    black_box(f());
+    start.elapsed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&s| Duration::from_millis(s)).collect()
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut samples = ms(&[5, 1, 4, 2, 3]);
+        let summary = Summary::from_samples(&mut samples);
+
+        assert_eq!(summary.runs, 5);
+        assert_eq!(summary.min, Duration::from_millis(1));
+        assert_eq!(summary.median, Duration::from_millis(3));
+        assert_eq!(summary.p95, Duration::from_millis(5));
+    }
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+        type AnswerA = u64;
+        type AnswerB = Result<u64, AocError>;
+
+        fn parse(lines: &[String]) -> Result<Vec<u64>, AocError> {
+            lines.iter().map(|l| crate::parse_number(l, "n")).collect()
+        }
+
+        fn part_a(numbers: &Vec<u64>) -> u64 {
+            numbers.iter().sum()
+        }
+
+        fn part_b(_: &Vec<u64>) -> Result<u64, AocError> {
+            Err(AocError::new("no part b"))
+        }
+
+        fn generate(_: &mut crate::rng::Rng, _: usize) -> Vec<String> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_solve_timed() {
+        let answers = solve_timed::<Sum>(&crate::load_str("1\n2"), &[Part::B, Part::A]).unwrap();
+
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[0].0, Part::B);
+        assert!(answers[0].1.is_err());
+        assert_eq!(answers[1].0, Part::A);
+        assert_eq!(answers[1].1, Ok(Answer::Integer(3)));
+
+        assert!(solve_timed::<Sum>(&crate::load_str("x"), &[Part::A]).is_err());
+    }
+
+    #[test]
+    fn test_count_allocations() {
+        let counts = count_allocations::<Sum>(&crate::load_str("1\n2")).unwrap();
+
+        assert_eq!(
+            counts
+                .iter()
+                .map(|&(phase, _)| phase)
+                .collect::<Vec<Phase>>(),
+            vec![Phase::Parse, Phase::PartA, Phase::PartB]
+        );
+        assert!(count_allocations::<Sum>(&crate::load_str("x")).is_err());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let samples = ms(&(1..=100).collect::<Vec<u64>>());
+
+        assert_eq!(percentile(&samples, 0), Duration::from_millis(1));
+        assert_eq!(percentile(&samples, 50), Duration::from_millis(50));
+        assert_eq!(percentile(&samples, 95), Duration::from_millis(95));
+        assert_eq!(percentile(&[], 95), Duration::ZERO);
+    }
+}
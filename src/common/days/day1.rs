@@ -0,0 +1,114 @@
+use std::cmp;
+
+use crate::rng::Rng;
+use crate::{parse_number, Alternative, AocError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(lines: &[String]) -> Result<Vec<i32>, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, x)| parse_number::<i32>(x, "a depth").map_err(|e| e.at_line(i + 1)))
+            .collect()
+    }
+
+    fn part_a(depths: &Vec<i32>) -> usize {
+        part1(depths)
+    }
+
+    fn part_b(depths: &Vec<i32>) -> usize {
+        part2(depths)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        let mut depth = rng.range(100, 200);
+
+        (0..size)
+            .map(|_| {
+                // the sea floor mostly slopes away from the shore
+                depth = cmp::max(0, depth + rng.range(-10, 20));
+                depth.to_string()
+            })
+            .collect()
+    }
+
+    fn alternatives_b() -> Vec<Alternative<Vec<i32>, usize>> {
+        vec![("part2_clever", |depths| part2_clever(depths))]
+    }
+}
+
+/// Counts how often a depth is deeper than the one before.
+pub fn part1(depths: &[i32]) -> usize {
+    depths
+        .windows(2)
+        .filter(|window| window[1] > window[0])
+        .count()
+}
+
+/// Counts how often the sum of a three-depth window grows.
+pub fn part2(depths: &[i32]) -> usize {
+    let mut increases = 0;
+
+    let moving_sums: Vec<i32> = depths.windows(3).map(|s| s.iter().sum()).collect();
+    for s in moving_sums.windows(2) {
+        if s[1] > s[0] {
+            increases += 1;
+        }
+    }
+
+    increases
+}
+
+/// Same as `part2`: consecutive windows share two depths, so only the ends need comparing.
+pub fn part2_clever(depths: &[i32]) -> usize {
+    depths
+        .windows(4)
+        .filter(|window| window[3] > window[0])
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::check_alternatives;
+    use crate::fuzz::{check_generator, check_parser};
+
+    #[test]
+    fn test_part2_clever() {
+        let depths = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+        assert_eq!(part1(&depths), 7);
+        assert_eq!(part2(&depths), 5);
+        assert_eq!(part2_clever(&depths), part2(&depths));
+    }
+
+    #[test]
+    fn test_alternatives_agree() {
+        check_alternatives::<Day1>(
+            &[
+                include_str!("../../../data/examples/day1.txt"),
+                include_str!("../../../data/day1.txt"),
+            ],
+            &[1, 3, 4, 10, 100],
+        );
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day1>(&[
+            include_str!("../../../data/examples/day1.txt"),
+            include_str!("../../../data/day1.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day1>(&[1, 10, 100]);
+    }
+}
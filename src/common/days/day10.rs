@@ -0,0 +1,283 @@
+use crate::rng::Rng;
+use crate::{AocError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type AnswerA = u64;
+    type AnswerB = Result<u64, AocError>;
+
+    fn parse(lines: &[String]) -> Result<Vec<String>, AocError> {
+        for (i, line) in lines.iter().enumerate() {
+            if let Some((column, c)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !"()[]{}<>".contains(*c))
+            {
+                return Err(AocError::new(format!("expected a bracket, found {:?}", c))
+                    .at_column(column + 1)
+                    .at_line(i + 1));
+            }
+        }
+
+        Ok(lines.to_vec())
+    }
+
+    fn part_a(lines: &Vec<String>) -> u64 {
+        part_a(lines)
+    }
+
+    fn part_b(lines: &Vec<String>) -> Result<u64, AocError> {
+        part_b(lines)
+    }
+
+    /// Writes a mix of corrupted and incomplete lines, starting with an incomplete one.
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        (0..size)
+            .map(|i| {
+                let corrupted = i > 0 && rng.chance(1, 2);
+                generate_line(rng, corrupted)
+            })
+            .collect()
+    }
+}
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Unclosed chunks are kept shallow enough for the completion score to fit in a `u64`.
+const MAX_OPEN: usize = 20;
+
+fn generate_line(rng: &mut Rng, corrupted: bool) -> String {
+    let mut line = String::new();
+    let mut open: Vec<(char, char)> = Vec::new();
+
+    for _ in 0..rng.range(20, 110) {
+        match open.pop() {
+            Some((_, close)) if open.len() + 1 >= MAX_OPEN || rng.chance(2, 5) => line.push(close),
+            popped => {
+                open.extend(popped);
+                let pair = *rng.choose(&PAIRS);
+                line.push(pair.0);
+                open.push(pair);
+            }
+        }
+    }
+
+    if open.is_empty() {
+        let pair = *rng.choose(&PAIRS);
+        line.push(pair.0);
+        open.push(pair);
+    }
+
+    if corrupted {
+        let expected = open[open.len() - 1].1;
+        let wrong: Vec<char> = PAIRS
+            .iter()
+            .map(|&(_, close)| close)
+            .filter(|&close| close != expected)
+            .collect();
+        line.push(*rng.choose(&wrong));
+    }
+
+    line
+}
+
+/// The total syntax error score of the corrupted lines.
+pub fn part_a(lines: &[String]) -> u64 {
+    let mut corrupted_sum: u64 = 0;
+    for line in lines {
+        match parse_line(line) {
+            Status::Corrupted(x) => {
+                corrupted_sum += x;
+            }
+            Status::Incomplete(_) => {}
+            Status::Complete => {}
+        }
+    }
+    corrupted_sum
+}
+
+/// The middle completion score of the incomplete lines.
+pub fn part_b(lines: &[String]) -> Result<u64, AocError> {
+    let mut scores: Vec<u64> = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        match parse_line(line) {
+            Status::Corrupted(_) => {}
+            Status::Incomplete(x) => {
+                let score = score_missing(&x).ok_or_else(|| {
+                    AocError::new("the completion score does not fit in 64 bits").at_line(i + 1)
+                })?;
+                scores.push(score);
+            }
+            Status::Complete => {}
+        }
+    }
+
+    if scores.is_empty() {
+        return Err(AocError::new("no line is incomplete"));
+    }
+
+    scores.sort();
+
+    Ok(scores[scores.len() / 2])
+}
+
+/// What checking a line found.
+#[derive(PartialEq, Eq, Debug)]
+pub enum Status {
+    /// The syntax error score of the first illegal closing character.
+    Corrupted(u64),
+    /// The chunks left open, innermost first.
+    Incomplete(Vec<char>),
+    Complete,
+}
+
+/// Checks the chunks on one line.
+pub fn parse_line(line: &str) -> Status {
+    let mut stack = Vec::<char>::new();
+
+    for c in line.chars() {
+        match c {
+            '(' => stack.push(c),
+            ')' => match stack.pop() {
+                Some('(') => {
+                    continue;
+                }
+                Some(_) => {
+                    return Status::Corrupted(points(')'));
+                }
+                None => {
+                    let mut missing = stack.clone();
+                    missing.reverse();
+                    return Status::Incomplete(missing);
+                }
+            },
+            '{' => stack.push(c),
+            '}' => match stack.pop() {
+                Some('{') => {
+                    continue;
+                }
+                Some(_) => return Status::Corrupted(points('}')),
+                None => {
+                    let mut missing = stack.clone();
+                    missing.reverse();
+                    return Status::Incomplete(missing);
+                }
+            },
+            '[' => stack.push(c),
+            ']' => match stack.pop() {
+                Some('[') => {
+                    continue;
+                }
+                Some(_) => return Status::Corrupted(points(']')),
+                None => {
+                    let mut missing = stack.clone();
+                    missing.reverse();
+                    return Status::Incomplete(missing);
+                }
+            },
+            '<' => stack.push(c),
+            '>' => match stack.pop() {
+                Some('<') => {
+                    continue;
+                }
+                Some(_) => return Status::Corrupted(points('>')),
+                None => {
+                    let mut missing = stack.clone();
+                    missing.reverse();
+                    return Status::Incomplete(missing);
+                }
+            },
+            _ => {
+                continue;
+            }
+        }
+    }
+
+    if stack.is_empty() {
+        Status::Complete
+    } else {
+        let mut missing = stack.clone();
+        missing.reverse();
+        Status::Incomplete(missing)
+    }
+}
+
+/// The syntax error score of an illegal closing character.
+pub fn points(c: char) -> u64 {
+    match c {
+        ')' => 3,
+        '}' => 1197,
+        ']' => 57,
+        '>' => 25137,
+        _ => 0,
+    }
+}
+
+/// The completion score of the chunks left open, innermost first, unless it overflows.
+pub fn score_missing(missing: &[char]) -> Option<u64> {
+    let mut score: u64 = 0;
+
+    for c in missing {
+        score = score.checked_mul(5)?;
+
+        score = score.checked_add(match c {
+            '(' => 1,
+            '{' => 3,
+            '[' => 2,
+            '<' => 4,
+            _ => 0,
+        })?;
+    }
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line("()"), Status::Complete);
+        assert_eq!(parse_line("("), Status::Incomplete(vec!['(']));
+        assert_eq!(parse_line("(("), Status::Incomplete(vec!['(', '(']));
+        assert_eq!(parse_line("((<"), Status::Incomplete(vec!['<', '(', '(']));
+        assert_eq!(parse_line("(>"), Status::Corrupted(points('>')));
+    }
+
+    #[test]
+    fn test_score() {
+        assert_eq!(score_missing(&[]), Some(0));
+        assert_eq!(score_missing(&['[', '(', '{', '<']), Some(294));
+        assert_eq!(score_missing(&['<'; 28]), None);
+    }
+
+    #[test]
+    fn test_part_b_errors() {
+        assert_eq!(
+            part_b(&crate::load_str("()\n(>")),
+            Err(AocError::new("no line is incomplete"))
+        );
+        assert_eq!(
+            part_b(&crate::load_str(&format!("()\n{}", "<".repeat(28)))),
+            Err(AocError::new("the completion score does not fit in 64 bits").at_line(2))
+        );
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day10>(&[
+            include_str!("../../../data/examples/day10.txt"),
+            include_str!("../../../data/day10.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day10>(&[1, 10, 100]);
+    }
+}
@@ -0,0 +1,556 @@
+use std::fmt::{self, Display};
+
+use crate::geometry::{Point, Rect};
+use crate::grid::{DenseGrid, Grid, SparseGrid};
+use crate::ocr::{read_letters, FONT, HEIGHT, PITCH, WIDTH};
+use crate::parse::{sections, Section};
+use crate::rng::Rng;
+use crate::{parse_number, AocError, Solution};
+use regex::Regex;
+
+/// The dots on the transparent paper and the folds to make, in order.
+pub struct Manual {
+    pub positions: SparseGrid<()>,
+    pub folds: Vec<Fold>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Manual;
+    type AnswerA = usize;
+    type AnswerB = Result<String, AocError>;
+
+    fn parse(input: &[String]) -> Result<Manual, AocError> {
+        match sections(input)[..] {
+            [ref positions, ref folds] => {
+                let positions = parse_positions(positions)?;
+                let folds = parse_folds(folds, unfolded_size(&positions))?;
+                Ok(Manual { positions, folds })
+            }
+            [_, _, ref extra, ..] => {
+                Err(AocError::new("unexpected text after the folds").at_line(extra.first_line))
+            }
+            _ => Err(AocError::new(
+                "expected a blank line between dots and folds",
+            )),
+        }
+    }
+
+    fn part_a(manual: &Manual) -> usize {
+        part_a(&manual.positions, &manual.folds)
+    }
+
+    fn part_b(manual: &Manual) -> Result<String, AocError> {
+        // check the size first, as drawing a sheet that cannot hold letters could take forever
+        let (width, height) = folded_size(&manual.positions, &manual.folds);
+        if height != HEIGHT as i64 {
+            return Err(AocError::new(format!(
+                "expected letters {} rows tall, found {} rows",
+                HEIGHT, height
+            )));
+        }
+        let whole_letters = matches!(width as usize % PITCH, 0 | WIDTH);
+        if width <= 0 || width > MAX_LETTERS * PITCH as i64 || !whole_letters {
+            return Err(AocError::new(format!(
+                "expected up to {} letters {} columns apart, found {} columns",
+                MAX_LETTERS, PITCH, width
+            )));
+        }
+
+        read_letters(&draw_folded(&manual.positions, &manual.folds))
+    }
+
+    /// Draws eight random letters and unfolds the paper, alternating axes, until there are
+    /// about `size` dots.
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        let mut dots = Vec::new();
+        for i in 0..8 {
+            let (_, glyph) = rng.choose(FONT);
+            for (y, row) in glyph.iter().enumerate() {
+                for (x, pixel) in row.chars().enumerate() {
+                    if pixel == '#' {
+                        dots.push(Point::new((i * PITCH + x) as i64, y as i64));
+                    }
+                }
+            }
+        }
+
+        let (mut width, mut height) = ((8 * PITCH) as i64, HEIGHT as i64);
+        let mut folds = Vec::new();
+        let mut along_x = rng.chance(1, 2);
+
+        while folds.len() < 2 || dots.len() < size {
+            let fold = if along_x {
+                Fold::X(width)
+            } else {
+                Fold::Y(height)
+            };
+
+            // each dot ends up on either side of the fold line, or on both
+            let mut unfolded = Vec::new();
+            for dot in dots {
+                let mirrored = match fold {
+                    Fold::X(x) => Point::new(2 * x - dot.x, dot.y),
+                    Fold::Y(y) => Point::new(dot.x, 2 * y - dot.y),
+                };
+                match rng.below(3) {
+                    0 => unfolded.push(dot),
+                    1 => unfolded.push(mirrored),
+                    _ => unfolded.extend([dot, mirrored]),
+                }
+            }
+            dots = unfolded;
+
+            match fold {
+                Fold::X(x) => width = 2 * x + 1,
+                Fold::Y(y) => height = 2 * y + 1,
+            }
+            folds.push(fold);
+            along_x = !along_x;
+        }
+
+        rng.shuffle(&mut dots);
+
+        let mut lines: Vec<String> = dots
+            .iter()
+            .map(|dot| format!("{},{}", dot.x, dot.y))
+            .collect();
+        lines.push(String::new());
+        lines.extend(folds.iter().rev().map(|fold| match fold {
+            Fold::X(x) => format!("fold along x={}", x),
+            Fold::Y(y) => format!("fold along y={}", y),
+        }));
+
+        lines
+    }
+}
+
+/// The longest message part B reads. Puzzles fold the paper down to eight letters.
+const MAX_LETTERS: i64 = 64;
+
+/// The number of dots visible after the first fold.
+pub fn part_a(positions: &SparseGrid<()>, folds: &[Fold]) -> usize {
+    let after = fold(positions, folds[0]);
+    after.len()
+}
+
+/// Folds the paper along every fold and draws what is left, one row per string.
+pub fn draw_folded(positions: &SparseGrid<()>, folds: &[Fold]) -> Vec<String> {
+    let sheet = fold_stages(positions, folds).pop().unwrap();
+    sheet.draw()
+}
+
+/// The paper at one stage of folding.
+pub struct Sheet {
+    pub dots: SparseGrid<()>,
+    pub width: i64,
+    pub height: i64,
+}
+
+impl Sheet {
+    /// The paper's area, or `None` if it folded away to nothing.
+    pub fn area(&self) -> Option<Rect> {
+        if self.width <= 0 || self.height <= 0 {
+            return None;
+        }
+        Some(Rect::new(
+            Point::ORIGIN,
+            Point::new(self.width - 1, self.height - 1),
+        ))
+    }
+
+    /// One row per string, `#` for a dot.
+    pub fn draw(&self) -> Vec<String> {
+        match self.area() {
+            Some(paper) => self
+                .dots
+                .render_area(paper, |dot| if dot.is_some() { '#' } else { '.' }),
+            None => Vec::new(),
+        }
+    }
+
+    /// Black dots on white paper, a pixel per position.
+    pub fn image(&self) -> DenseGrid<u8> {
+        let paper = match self.area() {
+            Some(paper) => paper,
+            None => return DenseGrid::new(0, 0, 255),
+        };
+        let mut image = DenseGrid::new(paper.width() as usize, paper.height() as usize, 255);
+        // a fold further from the edge than the paper is wide leaves dots off the paper
+        for (p, _) in self.dots.iter().filter(|&(p, _)| paper.contains(p)) {
+            image.set(p, 0);
+        }
+        image
+    }
+}
+
+/// The paper's width and height before folding, just large enough for every dot.
+fn unfolded_size(positions: &SparseGrid<()>) -> (i64, i64) {
+    positions
+        .bounding_box()
+        .map_or((0, 0), |area| (area.max.x + 1, area.max.y + 1))
+}
+
+/// The paper's width and height after one fold.
+fn size_after((width, height): (i64, i64), fold: Fold) -> (i64, i64) {
+    match fold {
+        Fold::X(x) => (x, height),
+        Fold::Y(y) => (width, y),
+    }
+}
+
+/// The paper's width and height after every fold, found without moving any dots.
+pub fn folded_size(positions: &SparseGrid<()>, folds: &[Fold]) -> (i64, i64) {
+    folds
+        .iter()
+        .fold(unfolded_size(positions), |size, &f| size_after(size, f))
+}
+
+/// The paper before any fold and after each one. It starts just large enough for every dot and
+/// shrinks with each fold.
+pub fn fold_stages(positions: &SparseGrid<()>, folds: &[Fold]) -> Vec<Sheet> {
+    let (width, height) = unfolded_size(positions);
+    let mut stages = vec![Sheet {
+        dots: positions.clone(),
+        width,
+        height,
+    }];
+
+    for &f in folds {
+        let last = stages.last().unwrap();
+        let (width, height) = size_after((last.width, last.height), f);
+        stages.push(Sheet {
+            dots: fold(&last.dots, f),
+            width,
+            height,
+        });
+    }
+
+    stages
+}
+
+/// Folds the paper once. Dots on the fold line disappear.
+pub fn fold(positions: &SparseGrid<()>, fold: Fold) -> SparseGrid<()> {
+    positions
+        .iter()
+        .filter_map(|(position, _)| fold_position(&position, fold))
+        .map(|position| (position, ()))
+        .collect()
+}
+
+/// Where a dot ends up after a fold, or `None` if it lies on the fold line.
+pub fn fold_position(pos: &Point, fold: Fold) -> Option<Point> {
+    match fold {
+        Fold::X(x) => {
+            if pos.x < x {
+                Some(*pos)
+            } else if pos.x == x {
+                // do nothing
+                None
+            } else {
+                Some(Point::new(2 * x - pos.x, pos.y))
+            }
+        }
+        Fold::Y(y) => {
+            if pos.y < y {
+                Some(*pos)
+            } else if pos.y == y {
+                // do nothing
+                None
+            } else {
+                Some(Point::new(pos.x, 2 * y - pos.y))
+            }
+        }
+    }
+}
+
+fn parse_positions(positions: &Section) -> Result<SparseGrid<()>, AocError> {
+    let mut p = SparseGrid::new();
+
+    for (pos, line) in positions.lines.iter().zip(positions.first_line..) {
+        let (x, y) = pos
+            .split_once(',')
+            .ok_or_else(|| AocError::new("expected \"x,y\"").at_line(line))?;
+
+        let position = Point::new(
+            coordinate(x, "column").map_err(|e| e.at_line(line))?,
+            coordinate(y, "row").map_err(|e| e.at_line(line))?,
+        );
+        p.set(position, ());
+    }
+
+    Ok(p)
+}
+
+/// Dots and fold lines further out than this could overflow the paper's size or a fold.
+const MAX_COORDINATE: i64 = u32::MAX as i64;
+
+/// Parses a dot's column or row, or a fold line, which is never left of or above the paper.
+fn coordinate(s: &str, what: &str) -> Result<i64, AocError> {
+    let expected = format!("a non-negative {}", what);
+    match parse_number::<i64>(s, &expected)? {
+        n if n < 0 => Err(AocError::new(format!(
+            "expected {}, found {:?}",
+            expected, s
+        ))),
+        n if n > MAX_COORDINATE => Err(AocError::new(format!(
+            "expected a {} of at most {}, found {:?}",
+            what, MAX_COORDINATE, s
+        ))),
+        n => Ok(n),
+    }
+}
+
+/// A fold line: `X(x)` folds the right half left, `Y(y)` folds the bottom half up.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fold {
+    X(i64),
+    Y(i64),
+}
+
+impl Display for Fold {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fold::X(x) => write!(f, "x={}", x),
+            Fold::Y(y) => write!(f, "y={}", y),
+        }
+    }
+}
+
+/// Parses the number in a fold instruction such as `x=5`, where `axis` is `x` or `y`.
+pub fn fold_line(axis: &str, line: &str) -> Result<Fold, AocError> {
+    let pos = coordinate(line, "fold line")?;
+    match axis {
+        "x" => Ok(Fold::X(pos)),
+        "y" => Ok(Fold::Y(pos)),
+        _ => Err(AocError::new(format!("expected x or y, found {:?}", axis))),
+    }
+}
+
+/// Checks that a fold line crosses a sheet `size` wide and tall, clear of its first column or
+/// row.
+pub fn check_fold(fold: Fold, size: (i64, i64)) -> Result<(), AocError> {
+    let (line, extent, unit) = match fold {
+        Fold::X(x) => (x, size.0, "columns wide"),
+        Fold::Y(y) => (y, size.1, "rows tall"),
+    };
+    if line < 1 || line >= extent {
+        return Err(AocError::new(format!(
+            "expected a fold line across the paper, which is {} {}, found {}",
+            extent, unit, fold
+        )));
+    }
+    Ok(())
+}
+
+/// Parses the folds, each of which must cross the paper `size` wide and tall as folded so far.
+fn parse_folds(folds: &Section, size: (i64, i64)) -> Result<Vec<Fold>, AocError> {
+    let mut f = Vec::new();
+    let mut size = size;
+
+    let re = Regex::new(r"^fold along (x|y)=(\d+)$").unwrap();
+    for (fold, line) in folds.lines.iter().zip(folds.first_line..) {
+        let captures = re
+            .captures(fold)
+            .ok_or_else(|| AocError::new("expected \"fold along x=N\" or \"fold along y=N\""))
+            .map_err(|e| e.at_line(line))?;
+
+        let fold = fold_line(
+            captures.get(1).unwrap().as_str(),
+            captures.get(2).unwrap().as_str(),
+        )
+        .and_then(|fold| check_fold(fold, size).map(|()| fold))
+        .map_err(|e| e.at_line(line))?;
+
+        size = size_after(size, fold);
+        f.push(fold);
+    }
+
+    Ok(f)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+    use crate::load_str;
+
+    const EXAMPLE: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn test_example() {
+        let manual = Day13::parse(&load_str(EXAMPLE)).unwrap();
+
+        assert_eq!(Day13::part_a(&manual), 17);
+        assert_eq!(
+            draw_folded(&manual.positions, &manual.folds),
+            vec!["#####", "#...#", "#...#", "#...#", "#####", ".....", "....."]
+        );
+
+        // the example folds into a square rather than letters
+        let err = Day13::part_b(&manual).unwrap_err();
+        assert!(err.reason.starts_with("expected letters 6 rows tall"));
+    }
+
+    #[test]
+    fn test_part_b_checks_the_size_before_drawing() {
+        let part_b = |input| Day13::part_b(&Day13::parse(&load_str(input)).unwrap());
+
+        assert_eq!(
+            part_b("0,0\n4000000000,0\n\nfold along x=3000000000"),
+            Err(AocError::new("expected letters 6 rows tall, found 1 rows"))
+        );
+        assert_eq!(
+            part_b("0,0\n4000000000,12\n\nfold along y=6\nfold along x=3000000000"),
+            Err(AocError::new(
+                "expected up to 64 letters 5 columns apart, found 3000000000 columns"
+            ))
+        );
+        assert_eq!(
+            part_b("0,0\n14,12\n\nfold along y=6\nfold along x=7"),
+            Err(AocError::new(
+                "expected up to 64 letters 5 columns apart, found 7 columns"
+            ))
+        );
+        assert!(part_b("0,0\n9,12\n\nfold along y=6\nfold along x=9")
+            .unwrap_err()
+            .reason
+            .starts_with("unrecognised letter"));
+    }
+
+    #[test]
+    fn test_fold_stages() {
+        let manual = Day13::parse(&load_str(EXAMPLE)).unwrap();
+        let stages = fold_stages(&manual.positions, &manual.folds);
+
+        assert_eq!(
+            stages
+                .iter()
+                .map(|sheet| (sheet.dots.len(), sheet.width, sheet.height))
+                .collect::<Vec<_>>(),
+            vec![(18, 11, 15), (17, 11, 7), (16, 5, 7)]
+        );
+
+        let image = stages[2].image();
+        assert_eq!((image.width(), image.height()), (5, 7));
+        assert_eq!(image.get(Point::new(0, 0)), Some(&0));
+        assert_eq!(image.get(Point::new(1, 1)), Some(&255));
+
+        // x=9 folds along x=2 to x=-5, off the paper
+        let manual = Day13::parse(&load_str("9,3\n4,1\n\nfold along x=2")).unwrap();
+        let folded = fold_stages(&manual.positions, &manual.folds).pop().unwrap();
+        let image = folded.image();
+        assert_eq!((image.width(), image.height()), (2, 4));
+        assert_eq!(image.get(Point::new(0, 1)), Some(&0));
+        assert_eq!(image.iter().filter(|&(_, &level)| level == 0).count(), 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day13::parse(&load_str("6,10\nfold along y=7"))
+            .err()
+            .unwrap();
+        assert_eq!(err.reason, "expected a blank line between dots and folds");
+
+        let err = Day13::parse(&load_str("6,10\n\nfold along z=7"))
+            .err()
+            .unwrap();
+        assert_eq!(err.line, Some(3));
+
+        let err = Day13::parse(&load_str("6,10\n-1,3\n\nfold along x=2"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            AocError::new("expected a non-negative column, found \"-1\"").at_line(2)
+        );
+
+        let err = Day13::parse(&load_str("9223372036854775807,0\n\nfold along x=2"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            AocError::new("expected a column of at most 4294967295, found \"9223372036854775807\"")
+                .at_line(1)
+        );
+
+        let err = Day13::parse(&load_str("6,10\n\nfold along x=5\nfold along x=5"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            AocError::new(
+                "expected a fold line across the paper, which is 5 columns wide, found x=5"
+            )
+            .at_line(4)
+        );
+
+        let err = Day13::parse(&load_str("6,10\n\nfold along y=4294967296"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            AocError::new("expected a fold line of at most 4294967295, found \"4294967296\"")
+                .at_line(3)
+        );
+    }
+
+    #[test]
+    fn test_fold_position_x() {
+        assert_eq!(
+            fold_position(&Point { x: 4, y: 0 }, Fold::X(2)),
+            Some(Point { x: 0, y: 0 })
+        );
+        assert_eq!(
+            fold_position(&Point { x: 3, y: 0 }, Fold::X(2)),
+            Some(Point { x: 1, y: 0 })
+        );
+    }
+
+    #[test]
+    fn test_fold_position_y() {
+        assert_eq!(
+            fold_position(&Point { x: 0, y: 4 }, Fold::Y(2)),
+            Some(Point { x: 0, y: 0 })
+        );
+        assert_eq!(
+            fold_position(&Point { x: 0, y: 3 }, Fold::Y(2)),
+            Some(Point { x: 0, y: 1 })
+        );
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day13>(&[
+            include_str!("../../../data/examples/day13.txt"),
+            include_str!("../../../data/day13.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day13>(&[1, 10, 100]);
+    }
+}
@@ -0,0 +1,655 @@
+use std::cmp;
+
+use crate::parse::first_line;
+use crate::rng::Rng;
+use crate::{AocError, Solution};
+use itertools::max;
+use itertools::min;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type AnswerA = u64;
+    type AnswerB = Result<u64, AocError>;
+
+    fn parse(lines: &[String]) -> Result<Packet, AocError> {
+        let bits = hex_to_bits(first_line(lines)?).map_err(|e| e.at_line(1))?;
+
+        let (mut packets, _) = parse(bits).map_err(|e| e.at_line(1))?;
+
+        if packets.is_empty() {
+            return Err(AocError::new("expected a packet").at_line(1));
+        }
+
+        Ok(packets.remove(0))
+    }
+
+    fn part_a(packet: &Packet) -> u64 {
+        sum_packet_versions(packet)
+    }
+
+    fn part_b(packet: &Packet) -> Result<u64, AocError> {
+        evaluate(packet)
+    }
+
+    /// Writes a sum packet wrapping about `size` packets in total.
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        let mut budget = size;
+        let mut subpackets = Vec::new();
+        while subpackets.is_empty() || (budget > 0 && subpackets.len() < MAX_SUBPACKETS) {
+            subpackets.push(generate_packet(rng, &mut budget, 1));
+        }
+
+        let mut bits = Vec::new();
+        let (version, by_count) = (rng.below(8), rng.chance(1, 2));
+        encode_operator(&mut bits, version, PacketType::Sum, subpackets, by_count);
+        while bits.len() % 8 != 0 {
+            bits.push(0);
+        }
+
+        let hex = bits
+            .chunks(4)
+            .map(|nibble| char::from_digit(bits_to_u(nibble) as u32, 16).unwrap())
+            .collect::<String>()
+            .to_uppercase();
+
+        vec![hex]
+    }
+}
+
+/// Operators can count at most 2047 subpackets, or 32767 bits of them.
+const MAX_SUBPACKETS: usize = (1 << 11) - 1;
+const MAX_SUBPACKET_BITS: usize = (1 << 15) - 1;
+const MAX_DEPTH: usize = 6;
+
+/// Writes one random packet and returns its bits. Products only multiply a few small
+/// literals, so evaluating the whole transmission stays within a `u64`.
+fn generate_packet(rng: &mut Rng, budget: &mut usize, depth: usize) -> Vec<u8> {
+    let mut bits = Vec::new();
+    *budget = budget.saturating_sub(1);
+
+    if *budget == 0 || depth >= MAX_DEPTH || rng.chance(1, 3) {
+        let (version, value) = (rng.below(8), rng.below(4096));
+        encode_literal(&mut bits, version, value);
+        return bits;
+    }
+
+    let packet_type = match rng.below(7) {
+        0 => PacketType::Sum,
+        1 => PacketType::Product,
+        2 => PacketType::Min,
+        3 => PacketType::Max,
+        4 => PacketType::GreaterThan,
+        5 => PacketType::LessThan,
+        _ => PacketType::EqualTo,
+    };
+    let count = match packet_type {
+        PacketType::GreaterThan | PacketType::LessThan | PacketType::EqualTo => 2,
+        _ => rng.range(1, 4) as usize,
+    };
+
+    let subpackets = (0..count)
+        .map(|_| match packet_type {
+            PacketType::Product => {
+                let mut literal = Vec::new();
+                let (version, value) = (rng.below(8), rng.range(1, 99) as u64);
+                encode_literal(&mut literal, version, value);
+                literal
+            }
+            _ => generate_packet(rng, budget, depth + 1),
+        })
+        .collect();
+
+    let (version, by_count) = (rng.below(8), rng.chance(1, 2));
+    encode_operator(&mut bits, version, packet_type, subpackets, by_count);
+    bits
+}
+
+fn encode_literal(bits: &mut Vec<u8>, version: u64, value: u64) {
+    push_bits(bits, version, 3);
+    push_bits(bits, PacketType::Literal as u64, 3);
+
+    let groups = cmp::max(1, (64 - value.leading_zeros() as usize).div_ceil(4));
+    for group in (0..groups).rev() {
+        push_bits(bits, (group > 0) as u64, 1);
+        push_bits(bits, value >> (group * 4), 4);
+    }
+}
+
+/// Writes an operator around already encoded subpackets, giving their number rather than their
+/// length when `by_count` is set or they are too long to measure in bits.
+fn encode_operator(
+    bits: &mut Vec<u8>,
+    version: u64,
+    packet_type: PacketType,
+    subpackets: Vec<Vec<u8>>,
+    by_count: bool,
+) {
+    push_bits(bits, version, 3);
+    push_bits(bits, packet_type as u64, 3);
+
+    let length: usize = subpackets.iter().map(|packet| packet.len()).sum();
+    if length <= MAX_SUBPACKET_BITS && !by_count {
+        push_bits(bits, 0, 1);
+        push_bits(bits, length as u64, 15);
+    } else {
+        push_bits(bits, 1, 1);
+        push_bits(bits, subpackets.len() as u64, 11);
+    }
+
+    bits.extend(subpackets.into_iter().flatten());
+}
+
+/// Appends the lowest `len` bits of `value`, most significant first.
+fn push_bits(bits: &mut Vec<u8>, value: u64, len: usize) {
+    bits.extend((0..len).rev().map(|i| ((value >> i) & 1) as u8));
+}
+
+/// The sum of the version numbers of a packet and all its subpackets.
+pub fn sum_packet_versions(packet: &Packet) -> u64 {
+    match &packet.content {
+        PacketContent::Operator { subpackets } => {
+            let sum: u64 = subpackets.iter().map(sum_packet_versions).sum();
+            sum + packet.version
+        }
+        _ => packet.version,
+    }
+}
+
+/// The value of the expression a packet encodes, unless a sum or product overflows.
+pub fn evaluate(packet: &Packet) -> Result<u64, AocError> {
+    let overflows = |operation| AocError::new(format!("the {} does not fit in 64 bits", operation));
+
+    Ok(match &packet.content {
+        PacketContent::Literal { value } => *value,
+        PacketContent::Operator { subpackets } => {
+            let sub: Vec<u64> = subpackets
+                .iter()
+                .map(evaluate)
+                .collect::<Result<_, AocError>>()?;
+
+            match packet.packet_type {
+                PacketType::Sum => sub
+                    .into_iter()
+                    .try_fold(0u64, |total, value| total.checked_add(value))
+                    .ok_or_else(|| overflows("sum"))?,
+                PacketType::Product => sub
+                    .into_iter()
+                    .try_fold(1u64, |total, value| total.checked_mul(value))
+                    .ok_or_else(|| overflows("product"))?,
+                PacketType::Max => max(sub).unwrap(),
+                PacketType::Min => min(sub).unwrap(),
+                PacketType::GreaterThan => {
+                    if sub[0] > sub[1] {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                PacketType::LessThan => {
+                    if sub[0] < sub[1] {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                PacketType::EqualTo => {
+                    if sub[0] == sub[1] {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                _ => {
+                    unreachable!()
+                }
+            }
+        }
+    })
+}
+
+#[derive(Debug)]
+pub enum PacketType {
+    Sum = 0,
+    Product = 1,
+    Min = 2,
+    Max = 3,
+    Literal = 4,
+    GreaterThan = 5,
+    LessThan = 6,
+    EqualTo = 7,
+}
+
+impl TryFrom<u64> for PacketType {
+    type Error = &'static str;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PacketType::Sum),
+            1 => Ok(PacketType::Product),
+            2 => Ok(PacketType::Min),
+            3 => Ok(PacketType::Max),
+            4 => Ok(PacketType::Literal),
+            5 => Ok(PacketType::GreaterThan),
+            6 => Ok(PacketType::LessThan),
+            7 => Ok(PacketType::EqualTo),
+            _ => Err("Invalid Packet Type ID"),
+        }
+    }
+}
+
+fn hex_to_bits(input: &str) -> Result<Vec<u8>, AocError> {
+    let bin = hex_to_bin(input)?;
+    Ok(bin_to_bits(&bin))
+}
+
+fn hex_to_bin(input: &str) -> Result<Vec<u8>, AocError> {
+    input
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match c.to_digit(16) {
+            Some(digit) => Ok(digit as u8),
+            None => {
+                Err(AocError::new(format!("expected a hex digit, found {:?}", c)).at_column(i + 1))
+            }
+        })
+        .collect()
+}
+
+fn bin_to_bits(input: &[u8]) -> Vec<u8> {
+    let mut res = Vec::new();
+
+    for bin in input {
+        let bits = [
+            (bin & 0b1000) >> 3,
+            (bin & 0b0100) >> 2,
+            (bin & 0b0010) >> 1,
+            (bin & 0b0001),
+        ];
+
+        res.extend(bits);
+    }
+
+    res
+}
+
+/// A decoded packet, with its subpackets if it is an operator.
+#[derive(Debug)]
+pub struct Packet {
+    pub version: u64,
+    pub packet_type: PacketType,
+
+    pub content: PacketContent,
+}
+
+#[derive(Debug)]
+pub enum PacketContent {
+    Literal { value: u64 },
+    Operator { subpackets: Vec<Packet> },
+}
+
+#[derive(Debug)]
+enum State {
+    PacketVersion,
+    PacketTypeId,
+    Literal,
+    Operator,
+}
+
+fn bits_to_u(bits: &[u8]) -> u64 {
+    let mut acc: u64 = 0;
+
+    for bit_index in (0..bits.len()).rev() {
+        acc += bits[bit_index] as u64 * (1 << (bits.len() - bit_index - 1))
+    }
+
+    acc
+}
+
+fn take(input: &[u8], bit_pos: usize, len: usize) -> Result<&[u8], AocError> {
+    input
+        .get(bit_pos..bit_pos + len)
+        .ok_or_else(|| AocError::new(format!("packet ends early, expected {} more bits", len)))
+}
+
+fn parse(input: Vec<u8>) -> Result<(Vec<Packet>, usize), AocError> {
+    parse_internal(input, None, 0)
+}
+
+fn operator(version: u64, type_id: u64, subpackets: Vec<Packet>) -> Result<Packet, AocError> {
+    let packet_type = PacketType::try_from(type_id).map_err(AocError::new)?;
+    let valid = match packet_type {
+        PacketType::GreaterThan | PacketType::LessThan | PacketType::EqualTo => {
+            subpackets.len() == 2
+        }
+        _ => !subpackets.is_empty(),
+    };
+    if !valid {
+        return Err(AocError::new(format!(
+            "{:?} packet cannot have {} subpackets",
+            packet_type,
+            subpackets.len()
+        )));
+    }
+
+    Ok(Packet {
+        version,
+        packet_type,
+        content: PacketContent::Operator { subpackets },
+    })
+}
+
+fn parse_internal(
+    input: Vec<u8>,
+    limit: Option<usize>,
+    level: usize,
+) -> Result<(Vec<Packet>, usize), AocError> {
+    // packet format
+    // packet version: 3 bit
+    // packet type id: 3 bit
+    // type id 4 => literal value, contains a single binary number
+    // groups for 4 bits, prefixed 1, or 0 for last group
+    // type id X => Operator.
+    // 1 or more subpackets
+
+    // length type id 1 bit
+    // 0
+    // 15 bits total length of the sub packets
+    // 1
+    // 11 bits with number of sub packes
+
+    let mut state = State::PacketVersion;
+    let mut packets = Vec::new();
+
+    let mut bit_pos = 0;
+    let mut cur_packet_version = 0;
+    let mut cur_packet_type_id = 0;
+
+    loop {
+        if bit_pos + 3 > input.len() && bits_to_u(&input[bit_pos..]) == 0 {
+            break;
+        }
+
+        if let Some(limit) = limit {
+            if packets.len() >= limit {
+                return Ok((packets, bit_pos));
+            }
+        }
+
+        match state {
+            State::PacketVersion => {
+                if bit_pos + 3 > input.len() {
+                    break;
+                }
+                cur_packet_version = bits_to_u(&input[bit_pos..bit_pos + 3]);
+
+                bit_pos += 3;
+                state = State::PacketTypeId
+            }
+            State::PacketTypeId => {
+                cur_packet_type_id = bits_to_u(take(&input, bit_pos, 3)?);
+                bit_pos += 3;
+                match cur_packet_type_id {
+                    4 => state = State::Literal,
+                    _ => state = State::Operator,
+                }
+            }
+            State::Operator => {
+                let length_type_bit = take(&input, bit_pos, 1)?[0];
+
+                bit_pos += 1;
+                match length_type_bit {
+                    0 => {
+                        let bit_length = bits_to_u(take(&input, bit_pos, 15)?);
+                        bit_pos += 15;
+
+                        let (subpackets, _) = parse_internal(
+                            take(&input, bit_pos, bit_length as usize)?.to_vec(),
+                            None,
+                            level + 1,
+                        )?;
+                        bit_pos += bit_length as usize;
+
+                        packets.push(operator(
+                            cur_packet_version,
+                            cur_packet_type_id,
+                            subpackets,
+                        )?);
+                        state = State::PacketVersion;
+                    }
+                    1 => {
+                        let num_sub_packets = bits_to_u(take(&input, bit_pos, 11)?);
+                        bit_pos += 11;
+
+                        let mut subpackets: Vec<Packet> = vec![];
+                        for _ in 0..num_sub_packets {
+                            let (parsed_packets, end_pos) =
+                                parse_internal(input[bit_pos..].to_vec(), Some(1), level + 1)?;
+                            if parsed_packets.is_empty() {
+                                return Err(AocError::new(format!(
+                                    "expected {} subpackets, found {}",
+                                    num_sub_packets,
+                                    subpackets.len()
+                                )));
+                            }
+                            subpackets.extend(parsed_packets);
+                            bit_pos += end_pos;
+                        }
+                        packets.push(operator(
+                            cur_packet_version,
+                            cur_packet_type_id,
+                            subpackets,
+                        )?);
+                        state = State::PacketVersion;
+                    }
+                    _ => {
+                        unreachable!()
+                    }
+                }
+            }
+            State::Literal => {
+                // continously take 5 bits
+                let mut literal: Vec<u8> = Vec::new();
+                loop {
+                    let group = take(&input, bit_pos, 5)?.to_vec();
+                    bit_pos += 5;
+                    literal.extend(group[1..5].iter());
+
+                    if literal.len() > 64 {
+                        return Err(AocError::new("literal value does not fit in 64 bits"));
+                    }
+
+                    if group[0] == 0 {
+                        // collect, end of packet
+                        let value = bits_to_u(&literal);
+                        packets.push(Packet {
+                            version: cur_packet_version,
+                            packet_type: PacketType::Literal,
+                            content: PacketContent::Literal { value },
+                        });
+
+                        state = State::PacketVersion;
+                        break;
+                    }
+                }
+            }
+        };
+
+        if bit_pos >= input.len() {
+            break;
+        }
+
+        if level == 0 && !packets.is_empty() {
+            break;
+        }
+    }
+
+    if !matches!(state, State::PacketVersion) {
+        return Err(AocError::new("packet ends early"));
+    }
+
+    Ok((packets, bit_pos))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+
+    #[test]
+    fn test_bits_to_u() {
+        assert_eq!(bits_to_u(&[0]), 0);
+        assert_eq!(bits_to_u(&[1]), 1);
+        assert_eq!(bits_to_u(&[1, 1]), 3);
+        assert_eq!(bits_to_u(&[1, 0, 0]), 4);
+        assert_eq!(bits_to_u(&[0, 0, 0, 1, 0, 1, 0, 0]), 20);
+    }
+
+    #[test]
+    fn test_hex_to_bits() {
+        assert_eq!(hex_to_bits("0").unwrap(), vec![0, 0, 0, 0]);
+        assert_eq!(hex_to_bits("1").unwrap(), vec![0, 0, 0, 1]);
+        assert_eq!(hex_to_bits("A").unwrap(), vec![1, 0, 1, 0]);
+        assert_eq!(hex_to_bits("12").unwrap(), vec![0, 0, 0, 1, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_hex_to_bin() {
+        assert_eq!(hex_to_bin("0").unwrap(), vec![0]);
+        assert_eq!(hex_to_bin("1").unwrap(), vec![1]);
+        assert_eq!(hex_to_bin("A").unwrap(), vec![10]);
+        assert_eq!(hex_to_bin("1G").unwrap_err().column, Some(2));
+    }
+
+    #[test]
+    fn test_parse_literal() {
+        let input = vec![
+            1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0,
+        ];
+        let (packets, _) = parse(input).unwrap();
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].version, 6);
+
+        if let PacketContent::Literal { value: v } = &packets[0].content {
+            assert_eq!(*v, 2021);
+        }
+    }
+
+    #[test]
+    fn test_parse_operator() {
+        let hex = "38006F45291200";
+        let bits = hex_to_bits(hex).unwrap();
+        let (packets, _) = parse(bits).unwrap();
+
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].version, 1);
+
+        if let PacketContent::Operator { subpackets: x } = &packets[0].content {
+            assert_eq!(x.len(), 2);
+
+            assert_eq!(x[0].version, 6);
+            if let PacketContent::Literal { value: v } = &x[0].content {
+                assert_eq!(*v, 10);
+            }
+
+            assert_eq!(x[1].version, 2);
+            if let PacketContent::Literal { value: v } = &x[1].content {
+                assert_eq!(*v, 20);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_operator_num_packets() {
+        let input = vec![
+            1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1,
+            1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
+        ];
+
+        let (packets, _) = parse(input).unwrap();
+
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].version, 7);
+
+        if let PacketContent::Operator { subpackets: x } = &packets[0].content {
+            assert_eq!(x.len(), 3)
+        }
+    }
+
+    #[test]
+    fn test_nested_operators() {
+        let hex = "A0016C880162017C3686B18A3D4780";
+
+        let bits = hex_to_bits(hex).unwrap();
+        let (packets, _) = parse(bits).unwrap();
+
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].version, 5);
+
+        if let PacketContent::Operator { subpackets: x } = &packets[0].content {
+            assert_eq!(x.len(), 1);
+            if let PacketContent::Operator { subpackets: y } = &x[0].content {
+                assert_eq!(y.len(), 1);
+                if let PacketContent::Operator { subpackets: z } = &y[0].content {
+                    assert_eq!(z.len(), 5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate_overflow() {
+        let evaluated = |packet_type| {
+            let literals = (0..2)
+                .map(|_| {
+                    let mut literal = Vec::new();
+                    encode_literal(&mut literal, 0, u64::MAX);
+                    literal
+                })
+                .collect();
+            let mut bits = Vec::new();
+            encode_operator(&mut bits, 0, packet_type, literals, false);
+            evaluate(&parse(bits).unwrap().0[0])
+        };
+
+        assert_eq!(
+            evaluated(PacketType::Sum),
+            Err(AocError::new("the sum does not fit in 64 bits"))
+        );
+        assert_eq!(
+            evaluated(PacketType::Product),
+            Err(AocError::new("the product does not fit in 64 bits"))
+        );
+        assert_eq!(evaluated(PacketType::Max), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_parse_invalid_packets() {
+        // literal cut off in its second group
+        let err = parse(hex_to_bits("D2FE").unwrap()).unwrap_err();
+        assert_eq!(err.reason, "packet ends early, expected 5 more bits");
+
+        // operator announcing three subpackets but holding two
+        let err = parse(hex_to_bits("EE00D40C82").unwrap()).unwrap_err();
+        assert_eq!(err.reason, "expected 3 subpackets, found 2");
+
+        // greater-than operator with a single subpacket
+        let err = parse(hex_to_bits("D6004408").unwrap()).unwrap_err();
+        assert_eq!(err.reason, "GreaterThan packet cannot have 1 subpackets");
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day16>(&[
+            include_str!("../../../data/examples/day16.txt"),
+            include_str!("../../../data/examples/day16-b.txt"),
+            include_str!("../../../data/day16.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day16>(&[1, 10, 100]);
+    }
+}
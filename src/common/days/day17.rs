@@ -0,0 +1,301 @@
+use std::cmp;
+
+use crate::geometry::{Point, Rect, Vector};
+use crate::image::Svg;
+use crate::parse::first_line;
+use crate::rng::Rng;
+use crate::{parse_number, AocError, Solution};
+use regex::Regex;
+
+/// Drag slows the probe towards zero horizontally while gravity pulls it down.
+fn decrease(velocity: Vector) -> Vector {
+    Vector::new(cmp::max(0, velocity.dx - 1), velocity.dy - 1)
+}
+
+/// Below or past the target there is no way back to it.
+fn has_overshot(target: &Rect, pos: Point) -> bool {
+    pos.x > target.max.x || pos.y < target.min.y
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Rect;
+    type AnswerA = i64;
+    type AnswerB = usize;
+
+    fn parse(lines: &[String]) -> Result<Rect, AocError> {
+        parse_area(first_line(lines)?).map_err(|e| e.at_line(1))
+    }
+
+    fn part_a(target_area: &Rect) -> i64 {
+        let (max_y, _) = search_max(Point::ORIGIN, target_area);
+        max_y
+    }
+
+    fn part_b(target_area: &Rect) -> usize {
+        let (_, hits) = search_max(Point::ORIGIN, target_area);
+        hits
+    }
+
+    /// Writes a target `size` steps or so away, kept within reach of the velocities searched.
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        let distance = cmp::min(size as i64, 120);
+
+        let x = rng.range(10, 10 + distance);
+        let y = -rng.range(5, 5 + distance);
+        let (width, height) = (rng.range(5, 30), rng.range(5, 30));
+
+        vec![format!(
+            "target area: x={}..{}, y={}..{}",
+            x,
+            x + width,
+            y - height,
+            y
+        )]
+    }
+}
+
+/// How far the target may lie from the launcher across, matching the fastest shot `hits` tries.
+const MAX_X: i64 = 999;
+/// How far the target may lie below the launcher, matching the steepest shot down `hits` tries.
+/// A target level with or above the launcher is not allowed: one level with it is hit by shots
+/// of any height.
+const MAX_DEPTH: i64 = 200;
+
+fn parse_area(str: &str) -> Result<Rect, AocError> {
+    let re = Regex::new(r"^target area: x=([-]*\d+)..([-]*\d+), y=([-]*\d+)..([-]*\d+)$").unwrap();
+
+    let captures = re
+        .captures(str)
+        .ok_or_else(|| AocError::new("expected \"target area: x=A..B, y=C..D\""))?;
+    let coord = |i, axis: &str, min: i64, max: i64| {
+        let s = captures.get(i).unwrap().as_str();
+        match parse_number::<i64>(s, "a coordinate")? {
+            n if n < min || n > max => Err(AocError::new(format!(
+                "expected {} between {} and {}, found {:?}",
+                axis, min, max, s
+            ))),
+            n => Ok(n),
+        }
+    };
+
+    let x1 = coord(1, "an x", -MAX_X, MAX_X)?;
+    let x2 = coord(2, "an x", -MAX_X, MAX_X)?;
+    let y1 = coord(3, "a y", -MAX_DEPTH, -1)?;
+    let y2 = coord(4, "a y", -MAX_DEPTH, -1)?;
+
+    Ok(Rect::new(Point::new(x1, y1), Point::new(x2, y2)))
+}
+
+/// How a shot ended: the highest point it reached if it hit the target.
+pub enum Score {
+    Hit(i64),
+    Miss,
+}
+
+/// The highest point reached by any shot from `start` that hits the target, and the number of velocities that hit it.
+pub fn search_max(start: Point, target: &Rect) -> (i64, usize) {
+    hits(start, *target).fold((0, 0), |(max_y, hits), (_, my)| {
+        (cmp::max(max_y, my), hits + 1)
+    })
+}
+
+/// Every velocity that sends a probe from `start` into the target, with the highest point it
+/// reaches on the way.
+pub fn hits(start: Point, target: Rect) -> impl Iterator<Item = (Vector, i64)> {
+    // Faster shots pass the target on the first step, or, when it is below `start`, on the
+    // first step after falling back through `start`.
+    let max_dx = cmp::min(999, target.max.x.saturating_sub(start.x));
+    let min_dy = cmp::max(-200, target.min.y.saturating_sub(start.y));
+    let max_dy = if target.max.y < start.y {
+        cmp::min(999, start.y.saturating_sub(target.min.y) - 1)
+    } else {
+        999
+    };
+
+    (1..=max_dx)
+        .flat_map(move |dx| (min_dy..=max_dy).map(move |dy| Vector::new(dx, dy)))
+        .filter_map(move |velocity| match trace(start, velocity, &target) {
+            Score::Hit(max_y) => Some((velocity, max_y)),
+            Score::Miss => None,
+        })
+}
+
+/// Follows one shot until it hits or misses the target.
+pub fn trace(start: Point, velocity: Vector, target: &Rect) -> Score {
+    trace_observed(start, velocity, target, |_, _| {})
+}
+
+/// Like `trace`, showing `observe` the probe's position and velocity before each step.
+pub fn trace_observed(
+    start: Point,
+    velocity: Vector,
+    target: &Rect,
+    mut observe: impl FnMut(Point, Vector),
+) -> Score {
+    let mut cur_pos = start;
+    let mut cur_vel = velocity;
+    let mut max_y = 0;
+
+    loop {
+        observe(cur_pos, cur_vel);
+        let new_pos = cur_pos + cur_vel;
+        if has_overshot(target, cur_pos) {
+            return Score::Miss;
+        }
+
+        if new_pos.y > max_y {
+            max_y = new_pos.y;
+        }
+
+        if target.contains(new_pos) {
+            return Score::Hit(max_y);
+        }
+
+        cur_vel = decrease(cur_vel);
+        cur_pos = new_pos;
+    }
+}
+
+/// The positions of one shot from `start`, up to where it hits or overshoots the target.
+pub fn trajectory(start: Point, velocity: Vector, target: &Rect) -> Vec<Point> {
+    let mut positions = vec![start];
+    let mut pos = start;
+    let mut velocity = velocity;
+
+    while !target.contains(pos) && !has_overshot(target, pos) {
+        pos += velocity;
+        velocity = decrease(velocity);
+        positions.push(pos);
+    }
+
+    positions
+}
+
+/// Draws the target and up to `shots` of the shots from `start` that hit it, spread over all of
+/// them, with the highest one in red. Up is up, unlike on a grid.
+pub fn draw_trajectories(start: Point, target: &Rect, shots: usize, scale: f64) -> Svg {
+    let hits: Vec<(Vector, i64)> = hits(start, *target).collect();
+    let highest = hits.iter().max_by_key(|&&(_, max_y)| max_y).copied();
+    let step = hits.len().div_ceil(shots.max(1)).max(1);
+
+    let mut paths: Vec<Vec<Point>> = hits
+        .iter()
+        .step_by(step)
+        .chain(highest.iter())
+        .map(|&(velocity, _)| {
+            trajectory(start, velocity, target)
+                .into_iter()
+                .map(|p| Point::new(p.x, -p.y))
+                .collect()
+        })
+        .collect();
+    let highest = highest.map(|_| paths.pop().unwrap());
+
+    let flipped = Rect::new(
+        Point::new(target.min.x, -target.max.y),
+        Point::new(target.max.x, -target.min.y),
+    );
+    let start = Point::new(start.x, -start.y);
+    let area = paths
+        .iter()
+        .chain(highest.iter())
+        .flatten()
+        .fold(flipped.including(start), |area, &p| area.including(p));
+
+    let mut svg = Svg::new(area, scale);
+    svg.rect(flipped, "fill:#8fd18f");
+    for path in &paths {
+        svg.polyline(path, "stroke:#4060c0;stroke-opacity:0.4;stroke-width:0.3");
+    }
+    if let Some(path) = &highest {
+        svg.polyline(path, "stroke:#e02020;stroke-width:0.6");
+    }
+    svg.circle(start, 1.0, "fill:black");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+    use crate::load_str;
+
+    fn example() -> Rect {
+        Day17::parse(&load_str(include_str!("../../../data/examples/day17.txt"))).unwrap()
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(search_max(Point::ORIGIN, &example()), (45, 112));
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let target = example();
+        let path = trajectory(Point::ORIGIN, Vector::new(7, 2), &target);
+
+        assert_eq!(path[1], Point::new(7, 2));
+        assert_eq!(path.last(), Some(&Point::new(28, -7)));
+        assert!(target.contains(*path.last().unwrap()));
+
+        let missed = trajectory(Point::ORIGIN, Vector::new(17, -4), &target);
+        assert!(!target.contains(*missed.last().unwrap()));
+    }
+
+    #[test]
+    fn test_trace_observed() {
+        let mut positions = Vec::new();
+        let score = trace_observed(Point::ORIGIN, Vector::new(6, 3), &example(), |p, _| {
+            positions.push(p)
+        });
+
+        assert!(matches!(score, Score::Hit(6)));
+        assert_eq!(positions.first(), Some(&Point::ORIGIN));
+        assert_eq!(positions.len(), 9);
+    }
+
+    #[test]
+    fn test_draw_trajectories() {
+        let svg = draw_trajectories(Point::ORIGIN, &example(), 10, 4.0).to_string();
+
+        assert_eq!(svg.matches("<polyline").count(), 11);
+        assert_eq!(svg.matches("stroke:#e02020").count(), 1);
+    }
+
+    #[test]
+    fn test_parse_far_target() {
+        assert_eq!(
+            Day17::parse(&load_str(
+                "target area: x=20..30, y=-9223372036854775807..-5"
+            ))
+            .err(),
+            Some(
+                AocError::new("expected a y between -200 and -1, found \"-9223372036854775807\"")
+                    .at_line(1)
+            )
+        );
+        assert_eq!(
+            Day17::parse(&load_str("target area: x=20..30, y=-10..0")).err(),
+            Some(AocError::new("expected a y between -200 and -1, found \"0\"").at_line(1))
+        );
+        assert_eq!(
+            Day17::parse(&load_str("target area: x=20..1000, y=-10..-5")).err(),
+            Some(AocError::new("expected an x between -999 and 999, found \"1000\"").at_line(1))
+        );
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day17>(&[
+            include_str!("../../../data/examples/day17.txt"),
+            include_str!("../../../data/day17.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day17>(&[1, 10]);
+    }
+}
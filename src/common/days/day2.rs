@@ -0,0 +1,133 @@
+use crate::rng::Rng;
+use crate::{parse_number, AocError, Solution};
+
+/// One step of the planned course.
+pub enum Command {
+    Up(i64),
+    Down(i64),
+    Forward(i64),
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+    type AnswerA = Result<i64, AocError>;
+    type AnswerB = Result<i64, AocError>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Command>, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, command)| parse_command(command).map_err(|e| e.at_line(i + 1)))
+            .collect()
+    }
+
+    fn part_a(commands: &Vec<Command>) -> Result<i64, AocError> {
+        part1(commands)
+    }
+
+    fn part_b(commands: &Vec<Command>) -> Result<i64, AocError> {
+        part2(commands)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        (0..size)
+            .map(|_| {
+                let name = rng.choose(&["forward", "down", "up"]);
+                format!("{} {}", name, rng.range(1, 9))
+            })
+            .collect()
+    }
+}
+
+/// Parses one line such as `forward 5`.
+pub fn parse_command(command: &str) -> Result<Command, AocError> {
+    let (name, value) = command
+        .split_once(' ')
+        .ok_or_else(|| AocError::new("expected \"<command> <value>\""))?;
+    let value =
+        parse_number::<i64>(value, "a distance").map_err(|e| e.at_column(name.len() + 2))?;
+
+    match name {
+        "forward" => Ok(Command::Forward(value)),
+        "up" => Ok(Command::Up(value)),
+        "down" => Ok(Command::Down(value)),
+        _ => Err(AocError::new(format!("unknown command {:?}", name)).at_column(1)),
+    }
+}
+
+fn too_far() -> AocError {
+    AocError::new("the course does not fit in 64 bits")
+}
+
+/// Follows the commands as plain moves and multiplies position by depth.
+pub fn part1(commands: &[Command]) -> Result<i64, AocError> {
+    let mut depth: i64 = 0;
+    let mut x: i64 = 0;
+
+    for command in commands {
+        match *command {
+            Command::Up(value) => depth = depth.checked_sub(value).ok_or_else(too_far)?,
+            Command::Down(value) => depth = depth.checked_add(value).ok_or_else(too_far)?,
+            Command::Forward(value) => x = x.checked_add(value).ok_or_else(too_far)?,
+        }
+    }
+
+    depth.checked_mul(x).ok_or_else(too_far)
+}
+
+/// Follows the commands with `up`/`down` changing the aim, and multiplies position by depth.
+pub fn part2(commands: &[Command]) -> Result<i64, AocError> {
+    let mut depth: i64 = 0;
+    let mut aim: i64 = 0;
+    let mut position: i64 = 0;
+
+    for command in commands {
+        match *command {
+            Command::Up(value) => aim = aim.checked_sub(value).ok_or_else(too_far)?,
+            Command::Down(value) => aim = aim.checked_add(value).ok_or_else(too_far)?,
+            Command::Forward(value) => {
+                position = position.checked_add(value).ok_or_else(too_far)?;
+                depth = aim
+                    .checked_mul(value)
+                    .and_then(|dive| depth.checked_add(dive))
+                    .ok_or_else(too_far)?;
+            }
+        }
+    }
+
+    depth.checked_mul(position).ok_or_else(too_far)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+
+    #[test]
+    fn test_overflow() {
+        let parse = |input| Day2::parse(&crate::load_str(input)).unwrap();
+
+        let commands = parse("forward 9223372036854775807\ndown 2");
+        assert_eq!(part1(&commands), Err(too_far()));
+        assert_eq!(part2(&commands), Ok(0));
+
+        let commands = parse("down 2\nforward 9223372036854775807");
+        assert_eq!(part2(&commands), Err(too_far()));
+        assert_eq!(part1(&parse("up 1\nforward 5")), Ok(-5));
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day2>(&[
+            include_str!("../../../data/examples/day2.txt"),
+            include_str!("../../../data/day2.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day2>(&[1, 10, 100]);
+    }
+}
@@ -0,0 +1,264 @@
+use std::cmp;
+
+use crate::parse::digit_grid;
+use crate::rng::Rng;
+use crate::{AocError, Solution};
+
+pub struct Day3;
+
+/// Wider rows do not fit in an `i64`.
+const MAX_WIDTH: usize = 63;
+
+impl Solution for Day3 {
+    type Input = Vec<Vec<u8>>;
+    type AnswerA = Result<i64, AocError>;
+    type AnswerB = Result<i64, AocError>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Vec<u8>>, AocError> {
+        if lines.is_empty() {
+            return Err(AocError::new("expected a row of bits").at_line(1));
+        }
+        let rows = digit_grid(lines, 2)?;
+        if rows[0].len() > MAX_WIDTH {
+            return Err(AocError::new(format!(
+                "expected at most {} bits per row, found {}",
+                MAX_WIDTH,
+                rows[0].len()
+            ))
+            .at_line(1));
+        }
+
+        Ok(rows)
+    }
+
+    fn part_a(rows: &Vec<Vec<u8>>) -> Result<i64, AocError> {
+        part1(rows)
+    }
+
+    fn part_b(rows: &Vec<Vec<u8>>) -> Result<i64, AocError> {
+        part2(rows)
+    }
+
+    /// Writes at least two distinct rows, at least 12 bits wide.
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        let size = cmp::max(size, 2);
+        let mut width = 12;
+        while size > 1 << width {
+            width += 1;
+        }
+
+        let mut rows = Vec::new();
+        generate_rows(rng, String::new(), size, width, &mut rows);
+        rng.shuffle(&mut rows);
+
+        rows
+    }
+}
+
+/// Splits `count` rows between both values of the next bit. Whenever two or more rows share a
+/// prefix both values occur, so the rating searches always narrow down to exactly one row.
+fn generate_rows(
+    rng: &mut Rng,
+    prefix: String,
+    count: usize,
+    width: usize,
+    rows: &mut Vec<String>,
+) {
+    if prefix.len() == width {
+        rows.push(prefix);
+        return;
+    }
+    if count == 1 {
+        let rest = (prefix.len()..width).map(|_| *rng.choose(&['0', '1']));
+        rows.push(prefix.chars().chain(rest).collect());
+        return;
+    }
+
+    let capacity: i64 = 1 << (width - prefix.len() - 1);
+    let zeros = rng.range(
+        cmp::max(1, count as i64 - capacity),
+        cmp::min(count as i64 - 1, capacity),
+    ) as usize;
+
+    generate_rows(rng, format!("{}0", prefix), zeros, width, rows);
+    generate_rows(rng, format!("{}1", prefix), count - zeros, width, rows);
+}
+
+/// The power consumption: gamma rate times epsilon rate.
+pub fn part1(rows: &[Vec<u8>]) -> Result<i64, AocError> {
+    let mut gamma_rate = Vec::new();
+    let mut epsilon_rate = Vec::new();
+    let width = rows[0].len();
+
+    for i in 0..width {
+        let res = most_common(rows, i);
+        gamma_rate.push(res);
+        epsilon_rate.push(if res == 1 { 0 } else { 1 });
+    }
+
+    let gamma_rate_dec: i64 = binary_to_dec(gamma_rate);
+    let epsilon_rate_dec: i64 = binary_to_dec(epsilon_rate);
+
+    gamma_rate_dec
+        .checked_mul(epsilon_rate_dec)
+        .ok_or_else(|| AocError::new("the power consumption does not fit in 64 bits"))
+}
+
+/// The life support rating: oxygen generator rating times CO2 scrubber rating.
+pub fn part2(rows: &[Vec<u8>]) -> Result<i64, AocError> {
+    let oxygen = binary_to_dec(search(rows, false).ok_or_else(|| not_found("oxygen generator"))?);
+    let co2 = binary_to_dec(search(rows, true).ok_or_else(|| not_found("CO2 scrubber"))?);
+    oxygen
+        .checked_mul(co2)
+        .ok_or_else(|| AocError::new("the life support rating does not fit in 64 bits"))
+}
+
+fn not_found(rating: &str) -> AocError {
+    AocError::new(format!(
+        "the {} rating does not narrow down to a single row",
+        rating
+    ))
+}
+
+/// The one row left by filtering on each bit in turn, if it comes down to one.
+fn search(rows: &[Vec<u8>], inverse: bool) -> Option<Vec<u8>> {
+    let width = rows[0].len();
+
+    let mut r = rows.to_owned();
+    for i in 0..width {
+        let needle = if inverse {
+            if most_common(&r, i) == 1 {
+                0
+            } else {
+                1
+            }
+        } else {
+            most_common(&r, i)
+        };
+        r = filter_by(r.clone(), needle, i);
+        if r.len() == 1 {
+            return Some(r[0].clone());
+        }
+    }
+    None
+}
+
+fn filter_by(rows: Vec<Vec<u8>>, value: u8, position: usize) -> Vec<Vec<u8>> {
+    rows.into_iter()
+        .filter(|row| row[position] == value)
+        .collect()
+}
+
+fn binary_to_dec(bin: Vec<u8>) -> i64 {
+    let width = bin.len() - 1;
+    bin.into_iter()
+        .enumerate()
+        .map(|(i, r)| (r as i64) * (1 << (width - i)))
+        .sum()
+}
+
+/// The most common bit in a column, `1` on a tie.
+pub fn most_common(rows: &[Vec<u8>], position: usize) -> u8 {
+    let mut counts = [0; 2];
+    for r in rows {
+        counts[r[position] as usize] += 1;
+    }
+
+    if counts[0] > counts[1] {
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+
+    #[test]
+    fn test_part1() {
+        let data = vec![vec![0, 0, 1], vec![1, 1, 1], vec![0, 0, 0]];
+        // 001  * 110 = 1 * 6
+        let res = part1(&data);
+
+        assert_eq!(res, Ok(6));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day3::parse(&[]),
+            Err(AocError::new("expected a row of bits").at_line(1))
+        );
+        assert_eq!(
+            Day3::parse(&crate::load_str(&"1".repeat(70))),
+            Err(AocError::new("expected at most 63 bits per row, found 70").at_line(1))
+        );
+        assert!(Day3::parse(&crate::load_str(&"1".repeat(63))).is_ok());
+    }
+
+    #[test]
+    fn test_wide_rows_overflow() {
+        let high = format!("10{}", "1".repeat(61));
+        let low = format!("01{}", "0".repeat(61));
+        let rows = Day3::parse(&crate::load_str(&format!("{}\n{}\n{}", high, high, low))).unwrap();
+
+        assert_eq!(
+            part1(&rows),
+            Err(AocError::new(
+                "the power consumption does not fit in 64 bits"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_part2_without_a_single_rating() {
+        assert_eq!(
+            part2(&[vec![0], vec![0]]),
+            Err(AocError::new(
+                "the oxygen generator rating does not narrow down to a single row"
+            ))
+        );
+        assert_eq!(
+            part2(&[vec![0, 0], vec![0, 1]]),
+            Err(AocError::new(
+                "the CO2 scrubber rating does not narrow down to a single row"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_binary_to_dec() {
+        let input = vec![0, 0, 0];
+        assert_eq!(binary_to_dec(input), 0);
+
+        let input = vec![0, 0, 1];
+        assert_eq!(binary_to_dec(input), 1);
+
+        let input = vec![0, 1, 0];
+        assert_eq!(binary_to_dec(input), 2);
+
+        let input = vec![1, 0, 0];
+        assert_eq!(binary_to_dec(input), 4);
+
+        let input = vec![1, 1, 0];
+        assert_eq!(binary_to_dec(input), 6);
+
+        let input = vec![1, 1, 1];
+        assert_eq!(binary_to_dec(input), 7);
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day3>(&[
+            include_str!("../../../data/examples/day3.txt"),
+            include_str!("../../../data/day3.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day3>(&[1, 10, 100]);
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::HashSet;
+
+use crate::parse::{csv_numbers, first_line, number_row, sections, Section};
+use crate::rng::Rng;
+use crate::{AocError, Solution};
+
+/// A bingo board, row by row.
+pub struct Board {
+    pub lines: Vec<Vec<u64>>,
+}
+
+pub struct Bingo {
+    /// The numbers in the order they are drawn.
+    pub numbers: Vec<u64>,
+    pub boards: Vec<Board>,
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Bingo;
+    type AnswerA = Result<u64, AocError>;
+    type AnswerB = Result<u64, AocError>;
+
+    fn parse(lines: &[String]) -> Result<Bingo, AocError> {
+        let numbers =
+            csv_numbers::<u64>(first_line(lines)?, "a drawn number").map_err(|e| e.at_line(1))?;
+
+        let sections = sections(lines);
+        if sections[0].first_line != 1 || sections[0].lines.len() != 1 {
+            return Err(AocError::new("expected a blank line after the drawn numbers").at_line(2));
+        }
+
+        let boards = sections[1..]
+            .iter()
+            .map(parse_board)
+            .collect::<Result<Vec<Board>, AocError>>()?;
+
+        if boards.is_empty() {
+            return Err(AocError::new("expected at least one board").at_line(2));
+        }
+
+        Ok(Bingo { numbers, boards })
+    }
+
+    fn part_a(bingo: &Bingo) -> Result<u64, AocError> {
+        part_a(&bingo.numbers, &bingo.boards)
+    }
+
+    fn part_b(bingo: &Bingo) -> Result<u64, AocError> {
+        part_b(&bingo.numbers, &bingo.boards)
+    }
+
+    /// Writes `size` boards of numbers that are all drawn, so every board wins.
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        let mut numbers: Vec<u64> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+
+        let drawn: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        let mut lines = vec![drawn.join(",")];
+
+        let mut on_boards = numbers.clone();
+        for _ in 0..size {
+            rng.shuffle(&mut on_boards);
+            lines.push(String::new());
+            for row in on_boards[..25].chunks(5) {
+                let row: Vec<String> = row.iter().map(|n| format!("{:>2}", n)).collect();
+                lines.push(row.join(" "));
+            }
+        }
+
+        lines
+    }
+}
+
+/// The final score of the first board to win.
+pub fn part_a(numbers: &[u64], boards: &[Board]) -> Result<u64, AocError> {
+    let (found_board_id, last_number_index) = find_first_board_score(numbers, boards)
+        .ok_or_else(|| AocError::new("no board wins before the numbers run out"))?;
+
+    let drawn_numbers: Vec<&u64> = numbers[0..last_number_index].iter().collect();
+    let last_drawn = numbers[last_number_index - 1];
+
+    final_score(&boards[found_board_id], drawn_numbers, last_drawn)
+}
+
+/// The final score of the last board to win.
+pub fn part_b(numbers: &[u64], boards: &[Board]) -> Result<u64, AocError> {
+    let (found_board_id, last_number_index) = find_last_board_score(numbers, boards)
+        .ok_or_else(|| AocError::new("not every board wins before the numbers run out"))?;
+    let drawn_numbers: Vec<&u64> = numbers[0..last_number_index].iter().collect();
+    let last_drawn = numbers[last_number_index - 1];
+
+    final_score(&boards[found_board_id], drawn_numbers, last_drawn)
+}
+
+/// The first board to win and how many numbers were drawn by then.
+pub fn find_first_board_score(numbers: &[u64], boards: &[Board]) -> Option<(usize, usize)> {
+    for i in 1..=numbers.len() {
+        let drawn: HashSet<&u64> = numbers[0..i].iter().collect();
+        for (board_id, board) in boards.iter().enumerate() {
+            if score_board(&drawn, board) {
+                return Some((board_id, i));
+            }
+        }
+    }
+
+    None
+}
+
+/// The last board to win and how many numbers were drawn by then, if every board wins.
+pub fn find_last_board_score(numbers: &[u64], boards: &[Board]) -> Option<(usize, usize)> {
+    find_last_board_score_observed(numbers, boards, |_, _| {})
+}
+
+/// Like `find_last_board_score`, showing `observe` how many numbers are drawn and which boards
+/// have won, in order, after each draw.
+pub fn find_last_board_score_observed(
+    numbers: &[u64],
+    boards: &[Board],
+    mut observe: impl FnMut(usize, &[usize]),
+) -> Option<(usize, usize)> {
+    let mut win_order: Vec<usize> = Vec::new();
+    let mut won: HashSet<usize> = HashSet::new();
+
+    for i in 1..=numbers.len() {
+        let drawn: HashSet<&u64> = numbers[0..i].iter().collect();
+        for (board_id, board) in boards.iter().enumerate() {
+            if won.contains(&board_id) {
+                continue;
+            }
+            if score_board(&drawn, board) {
+                win_order.push(board_id);
+                won.insert(board_id);
+            }
+        }
+        observe(i, &win_order);
+        if won.len() == boards.len() {
+            return win_order.last().map(|&last| (last, i));
+        }
+    }
+
+    None
+}
+
+/// The sum of the numbers on a board left unmarked, times the number that made it win.
+fn final_score(board: &Board, drawn_numbers: Vec<&u64>, last_drawn: u64) -> Result<u64, AocError> {
+    let drawn: HashSet<&u64> = drawn_numbers.into_iter().collect();
+
+    board
+        .lines
+        .iter()
+        .flatten()
+        .filter(|col| !drawn.contains(col))
+        .try_fold(0u64, |sum, col| sum.checked_add(*col))
+        .and_then(|sum| sum.checked_mul(last_drawn))
+        .ok_or_else(|| AocError::new("the final score does not fit in 64 bits"))
+}
+
+fn score_board(numbers: &HashSet<&u64>, board: &Board) -> bool {
+    for line in 0..board.lines.len() {
+        if score_line(numbers, board, line) {
+            return true;
+        }
+    }
+
+    for column in 0..board.lines[0].len() {
+        if score_column(numbers, board, column) {
+            return true;
+        }
+    }
+    false
+}
+
+fn score_line(numbers: &HashSet<&u64>, board: &Board, line: usize) -> bool {
+    let line = &board.lines[line];
+
+    line.iter()
+        .filter(|&number| numbers.contains(&number))
+        .collect::<Vec<&u64>>()
+        .len()
+        == 5
+}
+fn score_column(numbers: &HashSet<&u64>, board: &Board, column: usize) -> bool {
+    board
+        .lines
+        .clone()
+        .into_iter()
+        .filter(|line| numbers.contains(&line[column]))
+        .collect::<Vec<Vec<u64>>>()
+        .len()
+        == 5
+}
+
+fn parse_board(section: &Section) -> Result<Board, AocError> {
+    if section.lines.len() != 5 {
+        return Err(AocError::new("expected 5 rows per board").at_line(section.first_line));
+    }
+
+    let lines = section
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, row)| match parse_row(row) {
+            Ok(row) if row.len() == 5 => Ok(row),
+            Ok(_) => {
+                Err(AocError::new("expected 5 numbers per row").at_line(section.first_line + i))
+            }
+            Err(e) => Err(e.at_line(section.first_line + i)),
+        })
+        .collect::<Result<Vec<Vec<u64>>, AocError>>()?;
+
+    Ok(Board { lines })
+}
+
+fn parse_row(s: &str) -> Result<Vec<u64>, AocError> {
+    number_row::<u64>(s, "a board number")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+
+    #[test]
+    fn test_parse_row() {
+        assert_eq!(parse_row("1").unwrap(), vec![1]);
+        assert_eq!(parse_row(" 1 20 99  2").unwrap(), vec![1, 20, 99, 2]);
+        assert!(parse_row("1 x").is_err());
+    }
+
+    #[test]
+    fn test_find_last_board_score_observed() {
+        let example = include_str!("../../../data/examples/day4.txt");
+        let bingo = Day4::parse(&crate::load_str(example)).unwrap();
+        let mut seen = Vec::new();
+        let last = find_last_board_score_observed(&bingo.numbers, &bingo.boards, |drawn, won| {
+            seen.push((drawn, won.to_vec()))
+        });
+
+        assert_eq!(last, Some((1, 15)));
+        assert_eq!(seen.len(), 15);
+        assert_eq!(seen[10], (11, vec![]));
+        assert_eq!(seen[11], (12, vec![2]));
+        assert_eq!(seen[14], (15, vec![2, 0, 1]));
+    }
+
+    #[test]
+    fn test_boards_that_never_win() {
+        let bingo = Day4::parse(&crate::load_str(
+            "1,2,3\n\n 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            Day4::part_a(&bingo),
+            Err(AocError::new("no board wins before the numbers run out"))
+        );
+        assert_eq!(
+            Day4::part_b(&bingo),
+            Err(AocError::new(
+                "not every board wins before the numbers run out"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_board_winning_on_the_last_number() {
+        let bingo = Day4::parse(&crate::load_str(
+            "2,3,4,5,1\n\n 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            find_first_board_score(&bingo.numbers, &bingo.boards),
+            Some((0, 5))
+        );
+        assert_eq!(Day4::part_a(&bingo), Ok(310));
+        assert_eq!(Day4::part_b(&bingo), Ok(310));
+    }
+
+    #[test]
+    fn test_score_overflow() {
+        let bingo = Day4::parse(&crate::load_str(
+            "1,2,3,4,5\n\n 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 18446744073709551615",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            Day4::part_a(&bingo),
+            Err(AocError::new("the final score does not fit in 64 bits"))
+        );
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day4>(&[
+            include_str!("../../../data/examples/day4.txt"),
+            include_str!("../../../data/day4.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day4>(&[1, 10, 100]);
+    }
+}
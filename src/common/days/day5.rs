@@ -0,0 +1,376 @@
+use std::cmp;
+
+use crate::geometry::{Point, Vector};
+use crate::grid::{DenseGrid, Grid, SparseGrid};
+use crate::image::Rgb;
+use crate::rng::Rng;
+use crate::{AocError, Solution};
+
+/// The vents are mapped out to here, which keeps the number of points a line covers in check.
+const MAX_COORDINATE: i64 = 9999;
+
+fn parse_point(item: &str) -> Result<Point, AocError> {
+    let malformed = || AocError::new("expected \"x,y -> x,y\"");
+
+    // the vents are mapped from the origin, so no end lies left of or above it
+    let coordinate = |s: &str, axis: &str| match s.parse::<i64>() {
+        Ok(n) if n < 0 => Err(AocError::new(format!(
+            "expected a non-negative {}, found {:?}",
+            axis, s
+        ))),
+        Ok(n) if n > MAX_COORDINATE => Err(AocError::new(format!(
+            "expected a {} of at most {}, found {:?}",
+            axis, MAX_COORDINATE, s
+        ))),
+        Ok(n) => Ok(n),
+        Err(_) => Err(malformed()),
+    };
+
+    let (x, y) = item.split_once(',').ok_or_else(malformed)?;
+    Ok(Point::new(coordinate(x, "column")?, coordinate(y, "row")?))
+}
+
+/// A line of vents between two ends, both included.
+pub struct Line {
+    pub a: Point,
+    pub b: Point,
+}
+
+impl Line {
+    fn diagonal(&self) -> bool {
+        self.a.x != self.b.x && self.a.y != self.b.y
+    }
+
+    /// Every point the line covers. Diagonal lines cover nothing unless `include_diagonal`.
+    pub fn produce_covered_coords(&self, include_diagonal: bool) -> Vec<Point> {
+        if self.diagonal() && !include_diagonal {
+            return Vec::new();
+        }
+
+        // walk from the leftmost end so points come out in a stable order
+        let start = cmp::min(self.a, self.b);
+        let end = cmp::max(self.a, self.b);
+        let step = (end - start).signum();
+
+        (0..=start.chebyshev(end) as i64)
+            .map(|i| start + step * i)
+            .collect()
+    }
+}
+
+impl TryFrom<&str> for Line {
+    type Error = AocError;
+
+    fn try_from(item: &str) -> Result<Line, AocError> {
+        let (a, b) = item
+            .split_once(" -> ")
+            .ok_or_else(|| AocError::new("expected \"x,y -> x,y\""))?;
+
+        let line = Line {
+            a: parse_point(a)?,
+            b: parse_point(b)?,
+        };
+        let span = line.b - line.a;
+        if line.diagonal() && span.dx.abs() != span.dy.abs() {
+            return Err(AocError::new(
+                "expected a horizontal, vertical or 45 degree diagonal line",
+            ));
+        }
+
+        Ok(line)
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Line>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(lines: &[String]) -> Result<Vec<Line>, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Line::try_from(line.as_str()).map_err(|e| e.at_line(i + 1)))
+            .collect()
+    }
+
+    fn part_a(lines: &Vec<Line>) -> usize {
+        let layout = layout_lines(lines, false);
+        count_dangerous_spots(&layout)
+    }
+
+    fn part_b(lines: &Vec<Line>) -> usize {
+        let layout = layout_lines(lines, true);
+        count_dangerous_spots(&layout)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        const EDGE: i64 = 989;
+
+        (0..size)
+            .map(|_| {
+                let a = Point::new(rng.range(0, EDGE), rng.range(0, EDGE));
+                let b = match rng.below(3) {
+                    0 => Point::new(rng.range(0, EDGE), a.y),
+                    1 => Point::new(a.x, rng.range(0, EDGE)),
+                    _ => {
+                        let step = Vector::new(*rng.choose(&[-1, 1]), *rng.choose(&[-1, 1]));
+                        let room = |at: i64, d: i64| if d > 0 { EDGE - at } else { at };
+                        a + step * rng.range(0, cmp::min(room(a.x, step.dx), room(a.y, step.dy)))
+                    }
+                };
+                format!("{},{} -> {},{}", a.x, a.y, b.x, b.y)
+            })
+            .collect()
+    }
+}
+
+/// Counts the points where at least two lines overlap.
+pub fn count_dangerous_spots(layout: &SparseGrid<usize>) -> usize {
+    layout.count(|&intersecting_lines| intersecting_lines >= 2)
+}
+
+/// How many lines cover each point. Diagonal lines are left out unless `include_diagonal`.
+pub fn layout_lines(lines: &[Line], include_diagonal: bool) -> SparseGrid<usize> {
+    let mut layout = SparseGrid::new();
+
+    for line in lines {
+        for coord in line.produce_covered_coords(include_diagonal) {
+            *layout.entry(coord).or_insert(0) += 1;
+        }
+    }
+
+    layout
+}
+
+/// Draws the layout the way the puzzle does, with the number of lines covering each point.
+pub fn draw_layout(layout: &SparseGrid<usize>) -> Vec<String> {
+    layout.render(|lines| match lines {
+        Some(&n) => char::from_digit(n as u32, 10).unwrap_or('+'),
+        None => '.',
+    })
+}
+
+/// Colours each point from the origin on by how many lines cover it: black for none, then from
+/// blue for a single line to yellow for the most crowded points.
+pub fn heatmap(layout: &SparseGrid<usize>) -> DenseGrid<Rgb> {
+    let area = match layout.bounding_box() {
+        Some(area) => area.including(Point::ORIGIN),
+        None => return DenseGrid::new(0, 0, [0, 0, 0]),
+    };
+    let most = layout.iter().map(|(_, &lines)| lines).max().unwrap_or(1);
+
+    let mut image = DenseGrid::new(area.width() as usize, area.height() as usize, [0, 0, 0]);
+    for (p, &lines) in layout.iter() {
+        image.set(Point::ORIGIN + (p - area.min), heat(lines, most));
+    }
+    image
+}
+
+fn heat(lines: usize, most: usize) -> Rgb {
+    let t = if most > 1 {
+        (lines - 1) as f64 / (most - 1) as f64
+    } else {
+        1.0
+    };
+    let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+    [mix(30, 255), mix(60, 220), mix(170, 0)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+    use crate::load_str;
+
+    #[test]
+    fn test_part_a() {
+        let lines = vec![
+            Line::try_from("1,1 -> 1,3").unwrap(),
+            Line::try_from("1,1 -> 3,1").unwrap(),
+        ];
+        let layout = layout_lines(&lines, false);
+        assert_eq!(layout.get(Point { x: 1, y: 1 }).unwrap(), &2);
+        assert_eq!(layout.get(Point { x: 1, y: 2 }).unwrap(), &1);
+        assert_eq!(layout.get(Point { x: 1, y: 3 }).unwrap(), &1);
+        assert_eq!(layout.get(Point { x: 2, y: 1 }).unwrap(), &1);
+        assert_eq!(layout.get(Point { x: 3, y: 1 }).unwrap(), &1);
+        assert_eq!(layout.len(), 5);
+
+        assert_eq!(count_dangerous_spots(&layout), 1);
+
+        let lines = vec![
+            Line::try_from("1,3 -> 1,1").unwrap(),
+            Line::try_from("1,1 -> 3,1").unwrap(),
+        ];
+        let layout = layout_lines(&lines, false);
+        assert_eq!(layout.get(Point { x: 1, y: 1 }).unwrap(), &2);
+        assert_eq!(layout.get(Point { x: 1, y: 2 }).unwrap(), &1);
+        assert_eq!(layout.get(Point { x: 1, y: 3 }).unwrap(), &1);
+        assert_eq!(layout.get(Point { x: 2, y: 1 }).unwrap(), &1);
+        assert_eq!(layout.get(Point { x: 3, y: 1 }).unwrap(), &1);
+        assert_eq!(layout.len(), 5);
+
+        assert_eq!(count_dangerous_spots(&layout), 1);
+
+        let lines = vec![
+            Line::try_from("1,3 -> 1,1").unwrap(),
+            Line::try_from("1,1 -> 3,1").unwrap(),
+            Line::try_from("3,3 -> 3,1").unwrap(),
+            Line::try_from("1,1 -> 1,2").unwrap(),
+        ];
+        let layout = layout_lines(&lines, false);
+        assert_eq!(layout.len(), 7);
+
+        assert_eq!(count_dangerous_spots(&layout), 3);
+    }
+
+    #[test]
+    fn test_example_a() {
+        let lines = vec![
+            Line::try_from("0,9 -> 5,9").unwrap(),
+            Line::try_from("8,0 -> 0,8").unwrap(),
+            Line::try_from("9,4 -> 3,4").unwrap(),
+            Line::try_from("2,2 -> 2,1").unwrap(),
+            Line::try_from("7,0 -> 7,4").unwrap(),
+            Line::try_from("6,4 -> 2,0").unwrap(),
+            Line::try_from("0,9 -> 2,9").unwrap(),
+            Line::try_from("3,4 -> 1,4").unwrap(),
+            Line::try_from("0,0 -> 8,8").unwrap(),
+            Line::try_from("5,5 -> 8,2").unwrap(),
+        ];
+        let layout = layout_lines(&lines, false);
+
+        assert_eq!(count_dangerous_spots(&layout), 5);
+        assert_eq!(
+            draw_layout(&layout),
+            vec![
+                ".......1..",
+                "..1....1..",
+                "..1....1..",
+                ".......1..",
+                ".112111211",
+                "..........",
+                "..........",
+                "..........",
+                "..........",
+                "222111....",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_from() {
+        let line = Line::try_from("893,613 -> 380,613").unwrap();
+        assert_eq!(line.a, Point { x: 893, y: 613 });
+        assert_eq!(line.b, Point { x: 380, y: 613 });
+
+        assert!(Line::try_from("893,613 -> 380").is_err());
+        assert!(Line::try_from("893,613 - 380,613").is_err());
+        assert_eq!(
+            Line::try_from("893,-613 -> 380,613").err().unwrap().reason,
+            "expected a non-negative row, found \"-613\""
+        );
+        assert_eq!(
+            Line::try_from("0,0 -> 0,9223372036854775807")
+                .err()
+                .unwrap()
+                .reason,
+            "expected a row of at most 9999, found \"9223372036854775807\""
+        );
+        assert_eq!(
+            Line::try_from("0,0 -> 2,1").err().unwrap().reason,
+            "expected a horizontal, vertical or 45 degree diagonal line"
+        );
+        assert!(Line::try_from("9999,0 -> 0,9999").is_ok());
+    }
+
+    #[test]
+    fn test_produce_line_coords() {
+        let horizontal = Line {
+            a: Point { x: 1, y: 1 },
+            b: Point { x: 3, y: 1 },
+        };
+
+        assert_eq!(
+            horizontal.produce_covered_coords(false),
+            vec![
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 3, y: 1 }
+            ]
+        );
+
+        let vertical = Line {
+            a: Point { x: 1, y: 1 },
+            b: Point { x: 1, y: 3 },
+        };
+
+        assert_eq!(
+            vertical.produce_covered_coords(false),
+            vec![
+                Point { x: 1, y: 1 },
+                Point { x: 1, y: 2 },
+                Point { x: 1, y: 3 }
+            ]
+        );
+
+        let diagonal_down = Line {
+            a: Point { x: 1, y: 1 },
+            b: Point { x: 3, y: 3 },
+        };
+
+        assert_eq!(diagonal_down.produce_covered_coords(false), vec![]);
+        assert_eq!(
+            diagonal_down.produce_covered_coords(true),
+            vec![
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 2 },
+                Point { x: 3, y: 3 }
+            ]
+        );
+
+        let diagonal_up = Line {
+            a: Point { x: 3, y: 1 },
+            b: Point { x: 1, y: 3 },
+        };
+
+        assert_eq!(diagonal_up.produce_covered_coords(false), vec![]);
+        assert_eq!(
+            diagonal_up.produce_covered_coords(true),
+            vec![
+                Point { x: 1, y: 3 },
+                Point { x: 2, y: 2 },
+                Point { x: 3, y: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let lines =
+            Day5::parse(&load_str(include_str!("../../../data/examples/day5.txt"))).unwrap();
+        let image = heatmap(&layout_lines(&lines, true));
+
+        assert_eq!((image.width(), image.height()), (10, 10));
+        assert_eq!(image.get(Point::new(1, 0)), Some(&[0, 0, 0]));
+        assert_eq!(image.get(Point::new(0, 0)), Some(&[30, 60, 170]));
+        assert_eq!(image.get(Point::new(4, 4)), Some(&[255, 220, 0]));
+        assert_eq!(heatmap(&SparseGrid::new()).width(), 0);
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day5>(&[
+            include_str!("../../../data/examples/day5.txt"),
+            include_str!("../../../data/day5.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day5>(&[1, 10, 100]);
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use crate::parse::{csv_numbers, first_line};
+use crate::rng::Rng;
+use crate::{AocError, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = HashMap<u8, usize>;
+    type AnswerA = Result<usize, AocError>;
+    type AnswerB = Result<usize, AocError>;
+
+    fn parse(lines: &[String]) -> Result<HashMap<u8, usize>, AocError> {
+        let initial_state: Vec<u8> =
+            csv_numbers(first_line(lines)?, "a timer").map_err(|e| e.at_line(1))?;
+
+        let mut per_day: HashMap<u8, usize> = HashMap::new();
+
+        for day in initial_state {
+            *per_day.entry(day).or_insert(0) += 1;
+        }
+
+        Ok(per_day)
+    }
+
+    fn part_a(per_day: &HashMap<u8, usize>) -> Result<usize, AocError> {
+        simulate(per_day.clone(), 80).ok_or_else(too_many)
+    }
+
+    fn part_b(per_day: &HashMap<u8, usize>) -> Result<usize, AocError> {
+        simulate(per_day.clone(), 256).ok_or_else(too_many)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        let timers: Vec<String> = (0..size).map(|_| rng.range(1, 5).to_string()).collect();
+        vec![timers.join(",")]
+    }
+}
+
+fn too_many() -> AocError {
+    AocError::new("the number of fish does not fit in 64 bits")
+}
+
+/// The number of fish after `days` days, from the number of fish per timer value, unless it
+/// overflows.
+pub fn simulate(per_day: HashMap<u8, usize>, days: usize) -> Option<usize> {
+    simulate_observed(per_day, days, |_, _| {})
+}
+
+/// Like `simulate`, showing `observe` the fish per timer value at the start and after each day.
+pub fn simulate_observed(
+    per_day: HashMap<u8, usize>,
+    days: usize,
+    mut observe: impl FnMut(usize, &HashMap<u8, usize>),
+) -> Option<usize> {
+    let mut next = per_day;
+    observe(0, &next);
+
+    for day in 1..=days {
+        next = next_day(next)?;
+        observe(day, &next);
+    }
+
+    count(&next)
+}
+
+/// The number of fish, unless it overflows.
+pub fn count(per_day: &HashMap<u8, usize>) -> Option<usize> {
+    per_day
+        .values()
+        .try_fold(0usize, |total, &fish| total.checked_add(fish))
+}
+
+/// Advances every timer by one day, spawning new fish from those at zero, unless the number of
+/// fish at a timer value overflows.
+pub fn next_day(per_day: HashMap<u8, usize>) -> Option<HashMap<u8, usize>> {
+    let next: Vec<(u8, usize)> = per_day
+        .iter()
+        .flat_map(|(&cycle_days, &fish_count)| match cycle_days {
+            0 => vec![(6, fish_count), (8, fish_count)],
+            n => vec![(n - 1, fish_count)],
+        })
+        .collect();
+
+    let mut map: HashMap<u8, usize> = HashMap::new();
+
+    for (day, count) in next {
+        let fish = map.entry(day).or_insert(0);
+        *fish = fish.checked_add(count)?;
+    }
+
+    Some(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+
+    #[test]
+    fn test_simulate_observed() {
+        let per_day = Day6::parse(&crate::load_str("3,4,3,1,2")).unwrap();
+        let mut seen = Vec::new();
+        let fish = simulate_observed(per_day, 18, |day, per_day| {
+            seen.push((day, per_day.values().sum::<usize>()))
+        });
+
+        assert_eq!(fish, Some(26));
+        assert_eq!(seen.len(), 19);
+        assert_eq!(seen[0], (0, 5));
+        assert_eq!(seen[18], (18, 26));
+    }
+
+    #[test]
+    fn test_simulate_overflows() {
+        let per_day = Day6::parse(&crate::load_str("3,4,3,1,2")).unwrap();
+
+        assert_eq!(simulate(per_day.clone(), 256), Some(26984457539));
+        assert_eq!(simulate(per_day, 1000), None);
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day6>(&[
+            include_str!("../../../data/examples/day6.txt"),
+            include_str!("../../../data/day6.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day6>(&[1, 10, 100]);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::parse::{csv_numbers, first_line};
+use crate::rng::Rng;
+use crate::{AocError, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<u32>;
+    type AnswerA = i64;
+    type AnswerB = Result<u64, AocError>;
+
+    fn parse(lines: &[String]) -> Result<Vec<u32>, AocError> {
+        let mut numbers: Vec<u32> =
+            csv_numbers(first_line(lines)?, "a position").map_err(|e| e.at_line(1))?;
+
+        numbers.sort_unstable();
+
+        Ok(numbers)
+    }
+
+    fn part_a(numbers: &Vec<u32>) -> i64 {
+        part_a(numbers)
+    }
+
+    fn part_b(numbers: &Vec<u32>) -> Result<u64, AocError> {
+        part_b(numbers)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+        let positions: Vec<String> = (0..size).map(|_| rng.range(0, 1999).to_string()).collect();
+        vec![positions.join(",")]
+    }
+}
+
+/// The least fuel to align the sorted positions when each step costs one.
+pub fn part_a(numbers: &[u32]) -> i64 {
+    let midpoint = numbers.len() / 2;
+
+    let median = numbers[midpoint];
+
+    numbers
+        .iter()
+        .map(|&x| (median as i64 - x as i64).abs())
+        .sum()
+}
+
+/// The least fuel to align the positions when each step costs one more than the last, unless
+/// it does not fit in a `u64`.
+pub fn part_b(numbers: &[u32]) -> Result<u64, AocError> {
+    let sum: u64 = numbers.iter().map(|&x| x as u64).sum();
+    let mean = sum / numbers.len() as u64;
+
+    // the best position is within half a step of the mean, so try both neighbours
+    (mean..=mean + 1)
+        .filter_map(|target| {
+            numbers.iter().try_fold(0u64, |total, &x| {
+                total.checked_add(cost_b(target.abs_diff(x as u64))?)
+            })
+        })
+        .min()
+        .ok_or_else(|| AocError::new("the fuel needed does not fit in 64 bits"))
+}
+
+fn cost_b(n: u64) -> Option<u64> {
+    n.checked_mul(n + 1).map(|cost| cost / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuzz::{check_generator, check_parser};
+
+    #[test]
+    fn test_part_b_overflow() {
+        let numbers = Day7::parse(&crate::load_str("4000000000,4000000000")).unwrap();
+        assert_eq!(part_b(&numbers), Ok(0));
+
+        let spread = format!("{},{}", ["0"; 5].join(","), ["4000000000"; 5].join(","));
+        let numbers = Day7::parse(&crate::load_str(&spread)).unwrap();
+        assert_eq!(
+            part_b(&numbers),
+            Err(AocError::new("the fuel needed does not fit in 64 bits"))
+        );
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        check_parser::<Day7>(&[
+            include_str!("../../../data/examples/day7.txt"),
+            include_str!("../../../data/day7.txt"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        check_generator::<Day7>(&[1, 10, 100]);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::bench::{
+    bench, count_allocations, solve_timed, AllocationCounts, TimedAnswers, Timings,
+};
+use crate::differential::{alternatives, differ, Disagreement};
+use crate::rng::Rng;
+use crate::{solve, Answers, AocError, Part, Solution};
+
+/// Sonar Sweep: counting how often the sea floor gets deeper.
+pub mod day1;
+/// Syntax Scoring: finding corrupted and incomplete chunks of brackets.
+pub mod day10;
+/// Transparent Origami: folding dotted paper until it spells a code.
+pub mod day13;
+/// Packet Decoder: decoding and evaluating BITS transmissions.
+pub mod day16;
+/// Trick Shot: firing a probe into a target area.
+pub mod day17;
+/// Dive!: following the submarine's course.
+pub mod day2;
+/// Binary Diagnostic: reading rates and ratings out of bit columns.
+pub mod day3;
+/// Giant Squid: playing bingo to win or to lose.
+pub mod day4;
+/// Hydrothermal Venture: counting where lines of vents overlap.
+pub mod day5;
+/// Lanternfish: simulating an exponentially growing school of fish.
+pub mod day6;
+/// The Treachery of Whales: aligning crabs at the cheapest position.
+pub mod day7;
+
+/// Solves the requested parts of a day on its input lines.
+pub type Solver = fn(&[String], &[Part]) -> Result<Answers, AocError>;
+
+/// A registered day, with its solution's functions for callers that pick the day at run time.
+pub struct Day {
+    pub number: u32,
+    pub solve: Solver,
+    pub bench: fn(&[String], usize) -> Result<Timings, AocError>,
+    pub count_allocations: fn(&[String]) -> Result<AllocationCounts, AocError>,
+    pub solve_timed: fn(&[String], &[Part]) -> Result<TimedAnswers, AocError>,
+    pub generate: fn(&mut Rng, usize) -> Vec<String>,
+    pub alternatives: fn() -> Vec<(Part, &'static str)>,
+    pub differ: fn(&[String]) -> Result<Option<Disagreement>, AocError>,
+}
+
+const fn day<S: Solution>(number: u32) -> Day {
+    Day {
+        number,
+        solve: solve::<S>,
+        bench: bench::<S>,
+        count_allocations: count_allocations::<S>,
+        solve_timed: solve_timed::<S>,
+        generate: S::generate,
+        alternatives: alternatives::<S>,
+        differ: differ::<S>,
+    }
+}
+
+/// Every solved day in puzzle order, the one registry both the `aoc` binary and the C interface
+/// look days up in.
+pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(1),
+    day::<day2::Day2>(2),
+    day::<day3::Day3>(3),
+    day::<day4::Day4>(4),
+    day::<day5::Day5>(5),
+    day::<day6::Day6>(6),
+    day::<day7::Day7>(7),
+    day::<day10::Day10>(10),
+    day::<day13::Day13>(13),
+    day::<day16::Day16>(16),
+    day::<day17::Day17>(17),
+];
+
+pub fn find_day(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub fn solver(number: u32) -> Option<Solver> {
+    find_day(number).map(|day| day.solve)
+}
@@ -0,0 +1,271 @@
+use std::fmt::{self, Display};
+
+use crate::manifest::escape_answer;
+use crate::rng::Rng;
+use crate::{load_str, Alternative, Answer, AocError, IntoAnswer, Part, Solution};
+
+const GENERATED_SEEDS: u64 = 3;
+
+/// What an implementation answered, or why it could not.
+pub type Outcome = Result<Answer, AocError>;
+
+/// An alternative implementation answering differently from the main one, on the smallest
+/// input found where it still does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: Part,
+    pub alternative: &'static str,
+    pub expected: Outcome,
+    pub found: Outcome,
+    pub input: Vec<String>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "part {}: {} answered {} where the main implementation answered {}, on input:",
+            self.part,
+            self.alternative,
+            show(&self.found),
+            show(&self.expected)
+        )?;
+        write!(f, "{}", self.input.join("\n"))
+    }
+}
+
+fn show(outcome: &Outcome) -> String {
+    match outcome {
+        Ok(answer) => escape_answer(&answer.to_string()),
+        Err(err) => format!("an error ({})", err),
+    }
+}
+
+/// The alternative implementations of a day, by part and name.
+pub fn alternatives<S: Solution>() -> Vec<(Part, &'static str)> {
+    let a = S::alternatives_a()
+        .into_iter()
+        .map(|(name, _)| (Part::A, name));
+    let b = S::alternatives_b()
+        .into_iter()
+        .map(|(name, _)| (Part::B, name));
+    a.chain(b).collect()
+}
+
+/// Runs every alternative implementation on `lines` and returns the first to disagree with the
+/// main one, with the input shrunk to where it still does. Fails if `lines` do not parse.
+pub fn differ<S: Solution>(lines: &[String]) -> Result<Option<Disagreement>, AocError> {
+    let input = S::parse(lines)?;
+    let (part, alternative) = match mismatches::<S>(&input).first() {
+        Some(&(part, alternative, _, _)) => (part, alternative),
+        None => return Ok(None),
+    };
+
+    let still_differs = |candidate: &[String]| match S::parse(candidate) {
+        Ok(input) => mismatch::<S>(&input, part, alternative).is_some(),
+        Err(_) => false,
+    };
+    let input = minimize(lines.to_vec(), still_differs);
+
+    let parsed = S::parse(&input)?;
+    let (expected, found) =
+        mismatch::<S>(&parsed, part, alternative).expect("the shrunk input still disagrees");
+    Ok(Some(Disagreement {
+        part,
+        alternative,
+        expected,
+        found,
+        input,
+    }))
+}
+
+/// Checks the alternatives on each of `inputs` and on generated inputs of each size for a few
+/// seeds, and panics with the smallest input found where one disagrees.
+pub fn check_alternatives<S: Solution>(inputs: &[&str], sizes: &[usize]) {
+    assert!(
+        !alternatives::<S>().is_empty(),
+        "there are no alternative implementations to check"
+    );
+
+    let generated = (0..GENERATED_SEEDS)
+        .flat_map(|seed| sizes.iter().map(move |&size| (seed, size)))
+        .map(|(seed, size)| S::generate(&mut Rng::new(seed), size));
+
+    for lines in inputs.iter().map(|input| load_str(input)).chain(generated) {
+        match differ::<S>(&lines) {
+            Ok(None) => {}
+            Ok(Some(disagreement)) => panic!("{}", disagreement),
+            Err(err) => panic!("input failed to parse: {}\n{}", err, lines.join("\n")),
+        }
+    }
+}
+
+/// Each alternative whose outcome differs from the main implementation's, with both outcomes.
+type Mismatch = (Part, &'static str, Outcome, Outcome);
+
+fn mismatches<S: Solution>(input: &S::Input) -> Vec<Mismatch> {
+    let mut found = compare(Part::A, input, S::part_a, S::alternatives_a());
+    found.extend(compare(Part::B, input, S::part_b, S::alternatives_b()));
+    found
+}
+
+fn mismatch<S: Solution>(
+    input: &S::Input,
+    part: Part,
+    alternative: &str,
+) -> Option<(Outcome, Outcome)> {
+    mismatches::<S>(input)
+        .into_iter()
+        .find(|&(p, name, _, _)| p == part && name == alternative)
+        .map(|(_, _, expected, found)| (expected, found))
+}
+
+fn compare<I, A: IntoAnswer>(
+    part: Part,
+    input: &I,
+    main: fn(&I) -> A,
+    alternatives: Vec<Alternative<I, A>>,
+) -> Vec<Mismatch> {
+    if alternatives.is_empty() {
+        return Vec::new();
+    }
+
+    let expected = main(input).into_answer();
+    alternatives
+        .into_iter()
+        .map(|(name, alternative)| (name, alternative(input).into_answer()))
+        .filter(|(_, found)| *found != expected)
+        .map(|(name, found)| (part, name, expected.clone(), found))
+        .collect()
+}
+
+/// Shrinks `lines` for as long as `fails` holds of them: drops runs of lines, halving the run
+/// down to single lines, then single characters, and starts over until nothing more can go.
+pub fn minimize(mut lines: Vec<String>, fails: impl Fn(&[String]) -> bool) -> Vec<String> {
+    loop {
+        let before = lines.clone();
+
+        let mut run = lines.len().div_ceil(2).max(1);
+        loop {
+            let mut start = 0;
+            while start < lines.len() {
+                let mut candidate = lines.clone();
+                candidate.drain(start..(start + run).min(lines.len()));
+                if fails(&candidate) {
+                    lines = candidate;
+                } else {
+                    start += run;
+                }
+            }
+            if run == 1 {
+                break;
+            }
+            run = run.div_ceil(2);
+        }
+
+        for line in 0..lines.len() {
+            let mut chars: Vec<char> = lines[line].chars().collect();
+            let mut at = 0;
+            while at < chars.len() {
+                let mut candidate = lines.clone();
+                candidate[line] = chars[..at].iter().chain(&chars[at + 1..]).collect();
+                if fails(&candidate) {
+                    lines = candidate;
+                    chars.remove(at);
+                } else {
+                    at += 1;
+                }
+            }
+        }
+
+        if lines == before {
+            return lines;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::Day1;
+    use crate::parse_number;
+
+    /// Sums numbers, with an alternative that forgets the negative ones.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+        type AnswerA = i64;
+        type AnswerB = usize;
+
+        fn parse(lines: &[String]) -> Result<Vec<i64>, AocError> {
+            lines.iter().map(|l| parse_number(l, "a number")).collect()
+        }
+
+        fn part_a(numbers: &Vec<i64>) -> i64 {
+            numbers.iter().sum()
+        }
+
+        fn part_b(numbers: &Vec<i64>) -> usize {
+            numbers.len()
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+            (0..size).map(|_| rng.range(-99, 99).to_string()).collect()
+        }
+
+        fn alternatives_a() -> Vec<Alternative<Vec<i64>, i64>> {
+            vec![
+                ("reversed", |numbers| numbers.iter().rev().sum()),
+                ("positive_only", |numbers| {
+                    numbers.iter().filter(|&&n| n > 0).sum()
+                }),
+            ]
+        }
+    }
+
+    #[test]
+    fn test_minimize() {
+        let lines = load_str("12\n345\n678\n9");
+
+        let has_seven = |lines: &[String]| lines.iter().any(|line| line.contains('7'));
+        assert_eq!(minimize(lines.clone(), has_seven), ["7"]);
+
+        let two_lines = |lines: &[String]| lines.len() == 2;
+        assert_eq!(minimize(lines, two_lines), ["", ""]);
+    }
+
+    #[test]
+    fn test_differ() {
+        let lines = load_str("12\n-345\n67\n-8\n9");
+        let disagreement = differ::<Sum>(&lines).unwrap().unwrap();
+
+        assert_eq!(disagreement.part, Part::A);
+        assert_eq!(disagreement.alternative, "positive_only");
+        assert_eq!(disagreement.input, ["-8"]);
+        assert_eq!(disagreement.expected, Ok(Answer::Integer(-8)));
+        assert_eq!(disagreement.found, Ok(Answer::Integer(0)));
+        assert_eq!(
+            disagreement.to_string(),
+            "part A: positive_only answered 0 where the main implementation answered -8, on input:\n-8"
+        );
+
+        assert_eq!(differ::<Sum>(&load_str("1\n2")), Ok(None));
+        assert!(differ::<Sum>(&load_str("x")).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "positive_only answered")]
+    fn test_check_alternatives() {
+        check_alternatives::<Sum>(&["1\n2\n3"], &[1, 10]);
+    }
+
+    #[test]
+    fn test_alternatives() {
+        assert_eq!(alternatives::<Day1>(), [(Part::B, "part2_clever")]);
+        assert_eq!(
+            alternatives::<Sum>(),
+            [(Part::A, "reversed"), (Part::A, "positive_only")]
+        );
+    }
+}
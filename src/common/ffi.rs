@@ -0,0 +1,199 @@
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::{slice, str};
+
+use crate::{days, load_str, panic_message, Part};
+
+pub const AOC_OK: i32 = 0;
+pub const AOC_ERROR: i32 = 1;
+pub const AOC_UNKNOWN_DAY: i32 = 2;
+pub const AOC_INVALID_ARGUMENT: i32 = 3;
+pub const AOC_PANIC: i32 = 4;
+
+/// Every status `aoc_solve` returns, with what it means to a C caller.
+const STATUSES: &[(&str, i32, &str)] = &[
+    ("AOC_OK", AOC_OK, "The answer was found."),
+    (
+        "AOC_ERROR",
+        AOC_ERROR,
+        "The input could not be parsed or solved.",
+    ),
+    (
+        "AOC_UNKNOWN_DAY",
+        AOC_UNKNOWN_DAY,
+        "No day has that number.",
+    ),
+    (
+        "AOC_INVALID_ARGUMENT",
+        AOC_INVALID_ARGUMENT,
+        "The part is not 'a' or 'b', or the input is not UTF-8.",
+    ),
+    ("AOC_PANIC", AOC_PANIC, "The solver crashed."),
+];
+
+/// Every exported function, with its C prototype and documentation.
+const FUNCTIONS: &[(&str, &[&str])] = &[
+    (
+        "int32_t aoc_solve(uint32_t day, char part, const uint8_t *input, size_t len, char **answer);",
+        &[
+            "Solves one part ('a' or 'b') of a day on the `len` bytes of puzzle input at `input`.",
+            "Returns AOC_OK with the answer in `*answer`, or another status with why it failed in",
+            "`*answer`. Either string must be released with aoc_free. `input` may be NULL when",
+            "`len` is 0.",
+        ],
+    ),
+    (
+        "void aoc_free(char *s);",
+        &["Releases a string returned by aoc_solve. Does nothing for NULL."],
+    ),
+];
+
+/// Solves one part of a day on a buffer of puzzle input, see `header` for the C contract.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes unless `len` is 0, and `answer` must be null or
+/// point to writable storage for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: c_char,
+    input: *const u8,
+    len: usize,
+    answer: *mut *mut c_char,
+) -> i32 {
+    if answer.is_null() {
+        return AOC_INVALID_ARGUMENT;
+    }
+    if input.is_null() && len > 0 {
+        *answer = into_c_string("input is NULL".to_owned());
+        return AOC_INVALID_ARGUMENT;
+    }
+    let bytes = if len == 0 {
+        &[][..]
+    } else {
+        slice::from_raw_parts(input, len)
+    };
+
+    let (status, text) =
+        match panic::catch_unwind(AssertUnwindSafe(|| solve_buffer(day, part as u8, bytes))) {
+            Ok(Ok(text)) => (AOC_OK, text),
+            Ok(Err(failure)) => failure,
+            Err(payload) => (AOC_PANIC, panic_message(payload)),
+        };
+
+    *answer = into_c_string(text);
+    status
+}
+
+/// Releases a string returned by `aoc_solve`.
+///
+/// # Safety
+///
+/// `s` must be null or a string from `aoc_solve` that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+fn solve_buffer(day: u32, part: u8, input: &[u8]) -> Result<String, (i32, String)> {
+    let solver = days::solver(day)
+        .ok_or_else(|| (AOC_UNKNOWN_DAY, format!("no solution for day {}", day)))?;
+    let part = (part as char)
+        .to_string()
+        .parse::<Part>()
+        .map_err(|e| (AOC_INVALID_ARGUMENT, e.reason))?;
+    let input = str::from_utf8(input)
+        .map_err(|e| (AOC_INVALID_ARGUMENT, format!("input is not UTF-8: {}", e)))?;
+
+    let answers = solver(&load_str(input), &[part]).map_err(|e| (AOC_ERROR, e.to_string()))?;
+    match &answers[0].1 {
+        Ok(answer) => Ok(answer.to_string()),
+        Err(err) => Err((AOC_ERROR, err.to_string())),
+    }
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    // an error quoting the input could contain NUL, which C strings cannot
+    CString::new(s.replace('\0', "\\0"))
+        .expect("NUL bytes were escaped")
+        .into_raw()
+}
+
+/// The C header declaring the exported functions, as kept in `include/aoc2021.h`.
+pub fn header() -> String {
+    let mut header = String::from(
+        "/* Generated by `aoc header`, do not edit. */
+
+#ifndef AOC2021_H
+#define AOC2021_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+",
+    );
+
+    header.push('\n');
+    for (name, value, doc) in STATUSES {
+        header.push_str(&format!("/* {} */\n#define {} {}\n", doc, name, value));
+    }
+
+    for (prototype, doc) in FUNCTIONS {
+        header.push('\n');
+        for (i, line) in doc.iter().enumerate() {
+            let open = if i == 0 { "/*" } else { " *" };
+            header.push_str(&format!("{} {}\n", open, line));
+        }
+        header.push_str(" */\n");
+        header.push_str(prototype);
+        header.push('\n');
+    }
+
+    header.push_str(
+        "
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC2021_H */
+",
+    );
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_is_current() {
+        assert!(
+            include_str!("../../include/aoc2021.h") == header(),
+            "include/aoc2021.h is out of date, regenerate it with `aoc header > include/aoc2021.h`"
+        );
+    }
+
+    #[test]
+    fn test_solve_buffer() {
+        assert_eq!(
+            solve_buffer(16, b'a', b"8A004A801A8002F478"),
+            Ok("16".to_owned())
+        );
+        assert_eq!(
+            solve_buffer(16, b'c', b"").unwrap_err().0,
+            AOC_INVALID_ARGUMENT
+        );
+        assert_eq!(solve_buffer(99, b'a', b"").unwrap_err().0, AOC_UNKNOWN_DAY);
+        assert_eq!(
+            solve_buffer(16, b'a', b"\xff").unwrap_err().0,
+            AOC_INVALID_ARGUMENT
+        );
+        assert_eq!(solve_buffer(16, b'a', b"xyz").unwrap_err().0, AOC_ERROR);
+    }
+}
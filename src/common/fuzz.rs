@@ -0,0 +1,236 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::rng::Rng;
+use crate::{load_str, solve, IntoAnswer, Part, Solution};
+
+const ITERATIONS: usize = 300;
+const TIMEOUT: Duration = Duration::from_secs(5);
+const SEED: u64 = 2021;
+const GENERATED_SEEDS: u64 = 3;
+
+const ALPHABET: &[char] = &[
+    '0', '1', '2', '9', '-', ',', ' ', '.', '=', '>', ':', 'x', 'y', 'A', 'F', 'G', '(', ']', '{',
+    '<', '\t', '\r', 'é',
+];
+
+const TOKENS: &[&str] = &[
+    "",
+    "0",
+    "-1",
+    // numbers just past what the solvers parse into, and ones at the limits that parse
+    "99999999999999999999999",
+    "18446744073709551615",
+    "9223372036854775807",
+    "-9223372036854775808",
+    "4294967295",
+    "4000000000",
+    "1010101010101010101010101010101010101010101010101010101010101010101010",
+    " -> ",
+    "fold along x=",
+    "target area: x=",
+    "..",
+    "forward",
+];
+
+/// Throws random and mutated inputs at a day's parser, and both parts on those that parse, and
+/// panics, showing the input, if either panics or does not return within a few seconds. `seeds`
+/// are valid inputs to mutate.
+///
+/// Each input runs on its own thread, which cannot be stopped: one that times out keeps running
+/// in the background until the test process exits.
+pub fn check_parser<S: Solution + 'static>(seeds: &[&str]) {
+    let mut rng = Rng::new(SEED);
+
+    for i in 0..ITERATIONS {
+        let input = if i % 10 == 0 {
+            random_input(&mut rng)
+        } else {
+            let seed = *rng.choose(seeds);
+            let mut lines = load_str(seed);
+            for _ in 0..=rng.below(3) {
+                mutate(&mut rng, &mut lines);
+            }
+            lines
+        };
+
+        check_input::<S>(input);
+    }
+}
+
+/// Generates inputs of each size for a few seeds and panics, showing the input, unless they
+/// parse and both parts find an answer.
+pub fn check_generator<S: Solution>(sizes: &[usize]) {
+    for seed in 0..GENERATED_SEEDS {
+        for &size in sizes {
+            let lines = S::generate(&mut Rng::new(seed), size);
+
+            let failed = solve::<S>(&lines, &[Part::A, Part::B]).and_then(|answers| {
+                answers
+                    .into_iter()
+                    .try_for_each(|(_, answer)| answer.map(|_| ()))
+            });
+            if let Err(err) = failed {
+                panic!(
+                    "generated input (seed {}, size {}) failed: {}\n{}",
+                    seed,
+                    size,
+                    err,
+                    lines.join("\n")
+                );
+            }
+        }
+    }
+}
+
+fn check_input<S: Solution + 'static>(lines: Vec<String>) {
+    let (sender, receiver) = mpsc::channel();
+    let shown = lines.join("\n");
+
+    thread::spawn(move || {
+        if let Ok(input) = S::parse(&lines) {
+            let _ = S::part_a(&input).into_answer();
+            let _ = S::part_b(&input).into_answer();
+        }
+        let _ = sender.send(());
+    });
+
+    match receiver.recv_timeout(TIMEOUT) {
+        Ok(()) => {}
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            panic!(
+                "parser or parts did not finish within {:?} on input:\n{}",
+                TIMEOUT, shown
+            )
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            panic!("parser or parts panicked on input:\n{}", shown)
+        }
+    }
+}
+
+fn random_input(rng: &mut Rng) -> Vec<String> {
+    (0..rng.below(6))
+        .map(|_| {
+            (0..rng.below(40))
+                .map(|_| *rng.choose(ALPHABET))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+fn mutate(rng: &mut Rng, lines: &mut Vec<String>) {
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    let line = rng.below(lines.len() as u64) as usize;
+    let chars: Vec<char> = lines[line].chars().collect();
+    let at = rng.below(chars.len() as u64 + 1) as usize;
+
+    match rng.below(10) {
+        0 => {
+            lines.remove(line);
+        }
+        1 => {
+            let copy = lines[line].clone();
+            lines.insert(line, copy);
+        }
+        2 => lines.insert(line, String::new()),
+        3 => lines.truncate(line),
+        4 if !chars.is_empty() => {
+            let mut chars = chars;
+            chars.remove(at.min(chars.len() - 1));
+            lines[line] = chars.into_iter().collect();
+        }
+        5 => {
+            let mut chars = chars;
+            chars.insert(at, *rng.choose(ALPHABET));
+            lines[line] = chars.into_iter().collect();
+        }
+        6 => {
+            let token = rng.choose(TOKENS);
+            let (head, tail) = chars.split_at(at);
+            lines[line] = format!(
+                "{}{}{}",
+                head.iter().collect::<String>(),
+                token,
+                tail.iter().collect::<String>()
+            );
+        }
+        7 => {
+            let end = at + rng.below((chars.len() - at) as u64 + 1) as usize;
+            lines[line] = chars[..at].iter().chain(&chars[end..]).collect();
+        }
+        8 => lines[line] = swap_numbers(rng, &lines[line]),
+        _ => {
+            let other = rng.below(lines.len() as u64) as usize;
+            lines.swap(line, other);
+        }
+    }
+}
+
+/// Replaces one number on a line, or every one, with a token. Numbers at the limits only reach
+/// a solver when they take the place of a number in valid input.
+fn swap_numbers(rng: &mut Rng, line: &str) -> String {
+    let mut runs: Vec<String> = Vec::new();
+    for c in line.chars() {
+        match runs.last_mut() {
+            Some(run) if run.ends_with(|d: char| d.is_ascii_digit()) == c.is_ascii_digit() => {
+                run.push(c)
+            }
+            _ => runs.push(c.to_string()),
+        }
+    }
+
+    let numbers: Vec<usize> = (0..runs.len())
+        .filter(|&i| runs[i].starts_with(|d: char| d.is_ascii_digit()))
+        .collect();
+    if numbers.is_empty() {
+        return line.to_owned();
+    }
+
+    let token = *rng.choose(TOKENS);
+    if rng.chance(1, 2) {
+        for &i in &numbers {
+            runs[i] = token.to_owned();
+        }
+    } else {
+        runs[*rng.choose(&numbers)] = token.to_owned();
+    }
+    runs.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutate_is_seeded() {
+        let seed = load_str("0,9 -> 5,9\n8,0 -> 0,8");
+        let mutated = |s| {
+            let mut rng = Rng::new(s);
+            let mut lines = seed.clone();
+            for _ in 0..20 {
+                mutate(&mut rng, &mut lines);
+            }
+            lines
+        };
+
+        assert_eq!(mutated(1), mutated(1));
+    }
+
+    #[test]
+    fn test_swap_numbers() {
+        let swapped: Vec<String> = (0..200)
+            .map(|seed| swap_numbers(&mut Rng::new(seed), "forward 12, 345"))
+            .collect();
+
+        assert!(swapped.iter().all(|line| line.starts_with("forward ")));
+        assert!(swapped.contains(&"forward 4000000000, 4000000000".to_owned()));
+        assert!(swapped
+            .iter()
+            .any(|line| line.starts_with("forward 12, ") && line != "forward 12, 345"));
+        assert_eq!(swap_numbers(&mut Rng::new(1), "no numbers"), "no numbers");
+    }
+}
@@ -0,0 +1,283 @@
+use std::cmp;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on an integer grid. `y` grows in whichever direction the puzzle uses.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A displacement between two points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub dx: i64,
+    pub dy: i64,
+}
+
+/// An axis-aligned rectangle including both corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(&self, other: Point) -> u64 {
+        (*self - other).manhattan()
+    }
+
+    pub fn chebyshev(&self, other: Point) -> u64 {
+        (*self - other).chebyshev()
+    }
+
+    /// The four points sharing an edge with this one.
+    pub fn neighbors4(&self) -> [Point; 4] {
+        Vector::ORTHOGONAL.map(|v| *self + v)
+    }
+
+    /// The eight points sharing an edge or a corner with this one.
+    pub fn neighbors8(&self) -> [Point; 8] {
+        Vector::ALL_DIRECTIONS.map(|v| *self + v)
+    }
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { dx: 0, dy: 0 };
+
+    pub const ORTHOGONAL: [Vector; 4] = [
+        Vector::new(1, 0),
+        Vector::new(0, 1),
+        Vector::new(-1, 0),
+        Vector::new(0, -1),
+    ];
+
+    pub const ALL_DIRECTIONS: [Vector; 8] = [
+        Vector::new(1, 0),
+        Vector::new(1, 1),
+        Vector::new(0, 1),
+        Vector::new(-1, 1),
+        Vector::new(-1, 0),
+        Vector::new(-1, -1),
+        Vector::new(0, -1),
+        Vector::new(1, -1),
+    ];
+
+    pub const fn new(dx: i64, dy: i64) -> Vector {
+        Vector { dx, dy }
+    }
+
+    pub fn manhattan(&self) -> u64 {
+        self.dx.unsigned_abs() + self.dy.unsigned_abs()
+    }
+
+    pub fn chebyshev(&self) -> u64 {
+        cmp::max(self.dx.unsigned_abs(), self.dy.unsigned_abs())
+    }
+
+    /// The unit step in the direction of this vector along each axis.
+    pub fn signum(&self) -> Vector {
+        Vector::new(self.dx.signum(), self.dy.signum())
+    }
+}
+
+impl Rect {
+    /// The rectangle spanned by two opposite corners, in any order.
+    pub fn new(a: Point, b: Point) -> Rect {
+        Rect {
+            min: Point::new(cmp::min(a.x, b.x), cmp::min(a.y, b.y)),
+            max: Point::new(cmp::max(a.x, b.x), cmp::max(a.y, b.y)),
+        }
+    }
+
+    /// The smallest rectangle containing every point, or `None` if there are none.
+    pub fn bounding_box<'a>(points: impl IntoIterator<Item = &'a Point>) -> Option<Rect> {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+
+        Some(points.fold(Rect::new(first, first), |rect, &p| rect.including(p)))
+    }
+
+    /// Grows the rectangle just enough to contain `p`.
+    pub fn including(&self, p: Point) -> Rect {
+        Rect {
+            min: Point::new(cmp::min(self.min.x, p.x), cmp::min(self.min.y, p.y)),
+            max: Point::new(cmp::max(self.max.x, p.x), cmp::max(self.max.y, p.y)),
+        }
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> u64 {
+        self.max.x.abs_diff(self.min.x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.max.y.abs_diff(self.min.y) + 1
+    }
+
+    /// Every point in the rectangle, row by row from `min.y`.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.dx, self.y + v.dy)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        Point::new(self.x - v.dx, self.y - v.dy)
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.dx - other.dx, self.dy - other.dy)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, n: i64) -> Vector {
+        Vector::new(self.dx * n, self.dy * n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point::new(3, -2);
+        let v = Vector::new(1, 5);
+
+        assert_eq!(p + v, Point::new(4, 3));
+        assert_eq!(p - v, Point::new(2, -7));
+        assert_eq!(Point::new(4, 3) - p, v);
+        assert_eq!(-v, Vector::new(-1, -5));
+        assert_eq!(v * 3, Vector::new(3, 15));
+        assert_eq!(v + v - v, v);
+        assert_eq!(Vector::new(-7, 0).signum(), Vector::new(-1, 0));
+
+        let mut q = p;
+        q += v;
+        q -= v;
+        assert_eq!(q, p);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 1);
+        let b = Point::new(-2, 5);
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let p = Point::new(0, 0);
+
+        assert_eq!(p.neighbors4().len(), 4);
+        assert!(p.neighbors4().iter().all(|&n| n.manhattan(p) == 1));
+        assert!(p.neighbors8().iter().all(|&n| n.chebyshev(p) == 1));
+        assert!(p.neighbors8().contains(&Point::new(-1, 1)));
+    }
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect::new(Point::new(30, -5), Point::new(20, -10));
+
+        assert_eq!(rect.min, Point::new(20, -10));
+        assert_eq!(rect.max, Point::new(30, -5));
+        assert_eq!((rect.width(), rect.height()), (11, 6));
+        assert!(rect.contains(Point::new(20, -5)));
+        assert!(rect.contains(Point::new(25, -7)));
+        assert!(!rect.contains(Point::new(31, -7)));
+        assert!(!rect.contains(Point::new(25, -4)));
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point::new(6, 10), Point::new(0, 14), Point::new(9, 0)];
+
+        assert_eq!(
+            Rect::bounding_box(&points),
+            Some(Rect::new(Point::new(0, 0), Point::new(9, 14)))
+        );
+        assert_eq!(Rect::bounding_box(&[]), None);
+    }
+
+    #[test]
+    fn test_rect_points() {
+        let rect = Rect::new(Point::new(0, 0), Point::new(1, 1));
+
+        assert_eq!(
+            rect.points().collect::<Vec<Point>>(),
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::collections::hash_map::{self, HashMap};
+
+use crate::geometry::{Point, Rect};
+
+/// Values laid out on integer points, however they are stored.
+pub trait Grid<T> {
+    fn get(&self, p: Point) -> Option<&T>;
+    fn get_mut(&mut self, p: Point) -> Option<&mut T>;
+    fn set(&mut self, p: Point, value: T);
+
+    /// Every stored cell with its position.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a;
+
+    /// The smallest rectangle holding every stored cell, or `None` for an empty grid.
+    fn bounding_box(&self) -> Option<Rect>;
+
+    fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.iter().filter(|(_, value)| predicate(value)).count()
+    }
+
+    /// Draws `area` one row per string, top row first, with `cell` picking the character for
+    /// each point. Points with nothing stored are passed as `None`.
+    fn render_area(&self, area: Rect, cell: impl Fn(Option<&T>) -> char) -> Vec<String> {
+        (area.min.y..=area.max.y)
+            .map(|y| {
+                (area.min.x..=area.max.x)
+                    .map(|x| cell(self.get(Point::new(x, y))))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Draws the bounding box of the grid, see `render_area`.
+    fn render(&self, cell: impl Fn(Option<&T>) -> char) -> Vec<String> {
+        match self.bounding_box() {
+            Some(area) => self.render_area(area, cell),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// A grid that only stores the points that were set, for unbounded or mostly empty layouts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn entry(&mut self, p: Point) -> hash_map::Entry<'_, Point, T> {
+        self.cells.entry(p)
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(cells: I) -> SparseGrid<T> {
+        SparseGrid {
+            cells: cells.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> for SparseGrid<T> {
+    fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    fn set(&mut self, p: Point, value: T) {
+        self.cells.insert(p, value);
+    }
+
+    /// Cells come out in no particular order.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a,
+    {
+        self.cells.iter().map(|(&p, value)| (p, value))
+    }
+
+    fn bounding_box(&self) -> Option<Rect> {
+        Rect::bounding_box(self.cells.keys())
+    }
+}
+
+/// A fixed-size grid with a value at every point from `(0, 0)` to `(width - 1, height - 1)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> DenseGrid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> DenseGrid<T> {
+        DenseGrid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> DenseGrid<T> {
+    /// Builds a grid from rows such as those returned by `parse::digit_grid`. All rows must have
+    /// the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> DenseGrid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows of a grid must have the same length"
+        );
+
+        DenseGrid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, p: Point) -> Option<usize> {
+        let x = usize::try_from(p.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(p.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+}
+
+impl<T> Grid<T> for DenseGrid<T> {
+    fn get(&self, p: Point) -> Option<&T> {
+        self.index(p).map(|i| &self.cells[i])
+    }
+
+    fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Panics if `p` is outside the grid.
+    fn set(&mut self, p: Point, value: T) {
+        match self.index(p) {
+            Some(i) => self.cells[i] = value,
+            None => panic!("{:?} is outside a {}x{} grid", p, self.width, self.height),
+        }
+    }
+
+    /// Cells come out row by row from the top left.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a,
+    {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| (Point::new((i % width) as i64, (i / width) as i64), value))
+    }
+
+    fn bounding_box(&self) -> Option<Rect> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        Some(Rect::new(
+            Point::ORIGIN,
+            Point::new(self.width as i64 - 1, self.height as i64 - 1),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dot(value: Option<&bool>) -> char {
+        match value {
+            Some(true) => '#',
+            _ => '.',
+        }
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounding_box(), None);
+        assert!(grid.render(dot).is_empty());
+
+        grid.set(Point::new(-1, 2), true);
+        grid.set(Point::new(2, 0), true);
+        grid.set(Point::new(0, 1), false);
+        *grid.get_mut(Point::new(0, 1)).unwrap() = true;
+        *grid.entry(Point::new(2, 2)).or_insert(false) = true;
+
+        assert_eq!(grid.len(), 4);
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&true));
+        assert_eq!(grid.get(Point::new(1, 1)), None);
+        assert_eq!(grid.count(|&v| v), 4);
+        assert_eq!(
+            grid.bounding_box(),
+            Some(Rect::new(Point::new(-1, 0), Point::new(2, 2)))
+        );
+        assert_eq!(grid.render(dot), vec!["...#", ".#..", "#..#"]);
+        assert_eq!(
+            grid.render_area(Rect::new(Point::new(0, 0), Point::new(1, 1)), dot),
+            vec!["..", "#."]
+        );
+    }
+
+    #[test]
+    fn test_dense_grid() {
+        let mut grid = DenseGrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+
+        grid.set(Point::new(0, 1), 9);
+        *grid.get_mut(Point::new(1, 0)).unwrap() += 10;
+
+        assert_eq!(grid.count(|&v| v > 5), 3);
+        assert_eq!(
+            grid.iter().map(|(p, &v)| (p.x, p.y, v)).collect::<Vec<_>>(),
+            vec![
+                (0, 0, 1),
+                (1, 0, 12),
+                (2, 0, 3),
+                (0, 1, 9),
+                (1, 1, 5),
+                (2, 1, 6)
+            ]
+        );
+        assert_eq!(
+            grid.render(|v| char::from_digit(*v.unwrap() as u32 % 10, 10).unwrap()),
+            vec!["123", "956"]
+        );
+    }
+
+    #[test]
+    fn test_dense_grid_empty() {
+        let grid = DenseGrid::new(0, 0, false);
+
+        assert_eq!(grid.bounding_box(), None);
+        assert_eq!(grid.iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dense_grid_set_outside() {
+        DenseGrid::new(2, 2, false).set(Point::new(2, 0), true);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{self, Display};
+
+use crate::geometry::{Point, Rect};
+use crate::grid::{DenseGrid, Grid};
+
+/// A colour as red, green and blue levels.
+pub type Rgb = [u8; 3];
+
+/// Encodes grey levels, 0 black to 255 white, as a binary PGM image.
+pub fn pgm(image: &DenseGrid<u8>) -> Vec<u8> {
+    let mut bytes = format!("P5\n{} {}\n255\n", image.width(), image.height()).into_bytes();
+    bytes.extend(image.iter().map(|(_, &level)| level));
+    bytes
+}
+
+/// Encodes colours as a binary PPM image.
+pub fn ppm(image: &DenseGrid<Rgb>) -> Vec<u8> {
+    let mut bytes = format!("P6\n{} {}\n255\n", image.width(), image.height()).into_bytes();
+    bytes.extend(image.iter().flat_map(|(_, rgb)| *rgb));
+    bytes
+}
+
+/// Blows every pixel up into a `factor` by `factor` square, for pictures with a pixel per cell.
+pub fn upscale<T: Clone>(image: &DenseGrid<T>, factor: usize) -> DenseGrid<T> {
+    let rows = (0..image.height() * factor)
+        .map(|y| {
+            (0..image.width() * factor)
+                .map(|x| {
+                    let p = Point::new((x / factor) as i64, (y / factor) as i64);
+                    image.get(p).unwrap().clone()
+                })
+                .collect()
+        })
+        .collect();
+    DenseGrid::from_rows(rows)
+}
+
+/// A vector drawing whose coordinates are grid points, `y` growing downwards.
+pub struct Svg {
+    area: Rect,
+    scale: f64,
+    elements: Vec<String>,
+}
+
+impl Svg {
+    /// A drawing showing `area`, each unit `scale` pixels wide.
+    pub fn new(area: Rect, scale: f64) -> Svg {
+        Svg {
+            area,
+            scale,
+            elements: Vec::new(),
+        }
+    }
+
+    /// Fills the cells of `area` inclusively, so a single point is a unit square.
+    pub fn rect(&mut self, area: Rect, style: &str) {
+        self.elements.push(format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" style=\"{}\"/>",
+            area.min.x,
+            area.min.y,
+            area.width(),
+            area.height(),
+            style
+        ));
+    }
+
+    /// Joins the centres of the cells at `points`.
+    pub fn polyline(&mut self, points: &[Point], style: &str) {
+        let points: Vec<String> = points
+            .iter()
+            .map(|p| format!("{},{}", p.x as f64 + 0.5, p.y as f64 + 0.5))
+            .collect();
+        self.elements.push(format!(
+            "<polyline points=\"{}\" style=\"fill:none;{}\"/>",
+            points.join(" "),
+            style
+        ));
+    }
+
+    pub fn circle(&mut self, center: Point, radius: f64, style: &str) {
+        self.elements.push(format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" style=\"{}\"/>",
+            center.x as f64 + 0.5,
+            center.y as f64 + 0.5,
+            radius,
+            style
+        ));
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">",
+            self.area.width() as f64 * self.scale,
+            self.area.height() as f64 * self.scale,
+            self.area.min.x,
+            self.area.min.y,
+            self.area.width(),
+            self.area.height()
+        )?;
+        for element in &self.elements {
+            writeln!(f, "  {}", element)?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pgm() {
+        let image = DenseGrid::from_rows(vec![vec![0, 128, 255], vec![1, 2, 3]]);
+
+        assert_eq!(pgm(&image), b"P5\n3 2\n255\n\x00\x80\xff\x01\x02\x03");
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = DenseGrid::from_rows(vec![vec![[255, 0, 0]], vec![[0, 0, 255]]]);
+
+        assert_eq!(ppm(&image), b"P6\n1 2\n255\n\xff\x00\x00\x00\x00\xff");
+    }
+
+    #[test]
+    fn test_upscale() {
+        let image = DenseGrid::from_rows(vec![vec![1, 2]]);
+
+        assert_eq!(
+            upscale(&image, 2),
+            DenseGrid::from_rows(vec![vec![1, 1, 2, 2], vec![1, 1, 2, 2]])
+        );
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut svg = Svg::new(Rect::new(Point::new(-1, -1), Point::new(2, 0)), 10.0);
+        svg.rect(Rect::new(Point::new(0, 0), Point::new(1, 0)), "fill:red");
+        svg.polyline(&[Point::new(-1, -1), Point::new(2, 0)], "stroke:blue");
+        svg.circle(Point::ORIGIN, 0.5, "fill:black");
+
+        assert_eq!(
+            svg.to_string(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"40\" height=\"20\" viewBox=\"-1 -1 4 2\">
+  <rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" style=\"fill:red\"/>
+  <polyline points=\"-0.5,-0.5 2.5,0.5\" style=\"fill:none;stroke:blue\"/>
+  <circle cx=\"0.5\" cy=\"0.5\" r=\"0.5\" style=\"fill:black\"/>
+</svg>
+"
+        );
+    }
+}
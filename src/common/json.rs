@@ -0,0 +1,33 @@
+/// Quotes and escapes `s` as a JSON string.
+pub fn string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("data/day5.txt"), "\"data/day5.txt\"");
+        assert_eq!(string("#.\n.#"), "\"#.\\n.#\"");
+        assert_eq!(string("say \"hi\"\\"), "\"say \\\"hi\\\"\\\\\"");
+        assert_eq!(string("\u{1}"), "\"\\u0001\"");
+    }
+}
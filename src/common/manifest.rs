@@ -0,0 +1,136 @@
+use crate::{load_file, parse_number, AocError, Part};
+
+/// A recorded answer for one part of one day, solved on a particular input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u32,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Loads a manifest of recorded answers.
+///
+/// Each line holds `<day> <part> <input> <answer>`, separated by whitespace. Everything after
+/// the input is the answer, with newlines written as `\n`. Blank lines and lines starting with
+/// `#` are ignored.
+pub fn load_manifest(file_path: &str) -> Result<Vec<Expected>, AocError> {
+    parse_manifest(&load_file(file_path)?).map_err(|e| e.in_file(file_path))
+}
+
+pub fn parse_manifest(lines: &[String]) -> Result<Vec<Expected>, AocError> {
+    let mut expected = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut rest = line.trim();
+        let (day, part, input) = match (
+            next_field(&mut rest),
+            next_field(&mut rest),
+            next_field(&mut rest),
+        ) {
+            (Some(day), Some(part), Some(input)) if !rest.is_empty() => (day, part, input),
+            _ => {
+                return Err(
+                    AocError::new("expected \"<day> <part> <input> <answer>\"").at_line(i + 1)
+                )
+            }
+        };
+
+        expected.push(Expected {
+            day: parse_number::<u32>(day, "a day").map_err(|e| e.at_line(i + 1))?,
+            part: part.parse::<Part>().map_err(|e| e.at_line(i + 1))?,
+            input: input.to_owned(),
+            answer: unescape_answer(rest),
+        });
+    }
+
+    Ok(expected)
+}
+
+fn next_field<'a>(rest: &mut &'a str) -> Option<&'a str> {
+    let (field, remainder) = rest.split_once(char::is_whitespace)?;
+    *rest = remainder.trim_start();
+    Some(field)
+}
+
+/// Writes an answer on a single line, so multi-line answers fit in the manifest.
+pub fn escape_answer(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub fn unescape_answer(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load_str;
+
+    #[test]
+    fn test_parse_manifest() {
+        let lines = load_str(
+            "# day part input answer\n\n5 a data/day5.txt 5124\n13 B data/day13.txt #.\\n.#\n",
+        );
+        let expected = parse_manifest(&lines).unwrap();
+
+        assert_eq!(
+            expected,
+            vec![
+                Expected {
+                    day: 5,
+                    part: Part::A,
+                    input: "data/day5.txt".to_owned(),
+                    answer: "5124".to_owned(),
+                },
+                Expected {
+                    day: 13,
+                    part: Part::B,
+                    input: "data/day13.txt".to_owned(),
+                    answer: "#.\n.#".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_errors() {
+        let err = parse_manifest(&load_str("5 a data/day5.txt")).unwrap_err();
+        assert_eq!(err.line, Some(1));
+
+        let err =
+            parse_manifest(&load_str("5 a data/day5.txt 1\n5 c data/day5.txt 2")).unwrap_err();
+        assert_eq!(err.line, Some(2));
+
+        assert!(parse_manifest(&load_str("x a data/day5.txt 1")).is_err());
+    }
+
+    #[test]
+    fn test_escape_answer() {
+        for answer in ["5124", "#.\n.#", "a\\nb", "\\", ""] {
+            assert_eq!(unescape_answer(&escape_answer(answer)), answer);
+        }
+        assert_eq!(escape_answer("#.\n.#"), "#.\\n.#");
+    }
+}
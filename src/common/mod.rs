@@ -0,0 +1,436 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+pub use answer::{Answer, IntoAnswer};
+use rng::Rng;
+
+pub mod alloc;
+pub mod answer;
+pub mod bench;
+/// Every solved puzzle: the model each input parses into and the functions that solve it.
+pub mod days;
+pub mod differential;
+/// The C interface of the `cdylib`, declared in `include/aoc2021.h`.
+pub mod ffi;
+pub mod fuzz;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod json;
+pub mod manifest;
+pub mod ocr;
+pub mod parse;
+pub mod rng;
+
+/// An input that could not be loaded or parsed, pointing at where it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub reason: String,
+}
+
+impl AocError {
+    pub fn new(reason: impl Into<String>) -> AocError {
+        AocError {
+            file: None,
+            line: None,
+            column: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// Attaches a 1-based line number, unless a more specific one is already set.
+    pub fn at_line(mut self, line: usize) -> AocError {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Attaches a 1-based column number, unless a more specific one is already set.
+    pub fn at_column(mut self, column: usize) -> AocError {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    pub fn in_file(mut self, file_path: &str) -> AocError {
+        self.file.get_or_insert_with(|| file_path.to_owned());
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            let name = Path::new(file)
+                .file_name()
+                .map_or(file.as_str(), |n| n.to_str().unwrap_or(file));
+            write!(f, "{}:", name)?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{}:", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, "{}:", column)?;
+        }
+        if self.file.is_some() || self.line.is_some() || self.column.is_some() {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl Error for AocError {}
+
+pub const STDIN: &str = "<stdin>";
+
+/// Loads puzzle input from `file_path`, or from stdin when the path is `-`, smoothing over what
+/// inputs pick up when copied around: see `normalise`.
+pub fn load_file(file_path: &str) -> Result<Vec<String>, AocError> {
+    read_file(file_path).map(normalise)
+}
+
+/// Loads puzzle input like `load_file`, but reports the first line `normalise` would have
+/// changed instead of changing it.
+pub fn load_file_strict(file_path: &str) -> Result<Vec<String>, AocError> {
+    let lines = read_file(file_path)?;
+    let name = if file_path == "-" { STDIN } else { file_path };
+    check_normalised(&lines).map_err(|e| e.in_file(name))?;
+    Ok(lines)
+}
+
+fn read_file(file_path: &str) -> Result<Vec<String>, AocError> {
+    if file_path == "-" {
+        let stdin = io::stdin();
+        return lines_from_reader(stdin.lock()).map_err(|e| e.in_file(STDIN));
+    }
+
+    let f = File::open(file_path)
+        .map_err(|e| AocError::new(format!("unable to open file: {}", e)).in_file(file_path))?;
+
+    lines_from_reader(BufReader::new(f)).map_err(|e| e.in_file(file_path))
+}
+
+/// Reads lines as they are, without `normalise`, so even the `\r` of Windows line endings is kept.
+pub fn lines_from_reader<R: BufRead>(reader: R) -> Result<Vec<String>, AocError> {
+    let mut content = vec![];
+
+    for (i, line) in reader.split(b'\n').enumerate() {
+        let l = line
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| String::from_utf8(bytes).map_err(|e| e.to_string()))
+            .map_err(|reason| AocError::new(reason).at_line(i + 1))?;
+        content.push(l);
+    }
+
+    Ok(content)
+}
+
+/// Splits an in-memory input into lines the same way `load_file` does.
+pub fn load_str(input: &str) -> Vec<String> {
+    normalise(input.split('\n').map(|l| l.to_owned()).collect())
+}
+
+const BOM: char = '\u{feff}';
+
+/// Drops a byte order mark, the `\r` of Windows line endings, trailing whitespace and blank
+/// lines at the end, none of which any puzzle input means to have.
+pub fn normalise(mut lines: Vec<String>) -> Vec<String> {
+    if let Some(first) = lines.first_mut() {
+        if first.starts_with(BOM) {
+            first.remove(0);
+        }
+    }
+    for line in lines.iter_mut() {
+        line.truncate(line.trim_end().len());
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Fails at the first line `normalise` would change, saying what it would drop.
+pub fn check_normalised(lines: &[String]) -> Result<(), AocError> {
+    if lines.first().is_some_and(|first| first.starts_with(BOM)) {
+        return Err(AocError::new("starts with a byte order mark")
+            .at_column(1)
+            .at_line(1));
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        let content = line.trim_end();
+        if content.len() < line.len() {
+            let reason = if line.ends_with('\r') {
+                "ends with \\r, the input has Windows line endings"
+            } else {
+                "has trailing whitespace"
+            };
+            return Err(AocError::new(reason)
+                .at_column(content.chars().count() + 1)
+                .at_line(i + 1));
+        }
+    }
+
+    let blank = lines
+        .iter()
+        .rev()
+        .take_while(|line| line.is_empty())
+        .count();
+    if blank > 0 {
+        return Err(
+            AocError::new("blank line at the end of the input").at_line(lines.len() - blank + 1)
+        );
+    }
+
+    Ok(())
+}
+
+/// Parses `s` as a number, reporting what was expected if it is not one.
+pub fn parse_number<T: std::str::FromStr>(s: &str, expected: &str) -> Result<T, AocError> {
+    s.parse::<T>()
+        .map_err(|_| AocError::new(format!("expected {}, found {:?}", expected, s)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Part::A => f.pad("A"),
+            Part::B => f.pad("B"),
+        }
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Part, AocError> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(AocError::new(format!(
+                "expected part a or b, found {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// A day's puzzle: the input is parsed once into a model that both parts solve on.
+pub trait Solution {
+    type Input;
+    type AnswerA: IntoAnswer;
+    type AnswerB: IntoAnswer;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError>;
+    fn part_a(input: &Self::Input) -> Self::AnswerA;
+    fn part_b(input: &Self::Input) -> Self::AnswerB;
+
+    /// Writes a random input that `parse` accepts, with roughly `size` items (lines, numbers,
+    /// boards, packets, ...) so inputs can be made far bigger than the puzzle's.
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String>;
+
+    /// Other implementations of part A, which `differential` checks against `part_a`.
+    fn alternatives_a() -> Vec<Alternative<Self::Input, Self::AnswerA>> {
+        Vec::new()
+    }
+
+    /// Other implementations of part B, which `differential` checks against `part_b`.
+    fn alternatives_b() -> Vec<Alternative<Self::Input, Self::AnswerB>> {
+        Vec::new()
+    }
+}
+
+/// Another way of solving a part, by name, that must always agree with the main one.
+pub type Alternative<I, A> = (&'static str, fn(&I) -> A);
+
+/// Each requested part with its answer, or why that part could not find one.
+pub type Answers = Vec<(Part, Result<Answer, AocError>)>;
+
+/// Parses `lines` and solves the requested parts. Only a parse error fails them all, a part
+/// that fails keeps its error next to the other parts' answers.
+pub fn solve<S: Solution>(lines: &[String], parts: &[Part]) -> Result<Answers, AocError> {
+    let input = S::parse(lines)?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let answer = match part {
+                Part::A => S::part_a(&input).into_answer(),
+                Part::B => S::part_b(&input).into_answer(),
+            };
+            (part, answer)
+        })
+        .collect())
+}
+
+/// What a solver said when it panicked, from the payload a joined thread or `catch_unwind`
+/// hands back.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "the solver panicked".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn test_panic_message() {
+        let message = |f: fn()| panic_message(panic::catch_unwind(f).unwrap_err());
+
+        assert_eq!(message(|| panic!("no board wins")), "no board wins");
+        assert_eq!(message(|| panic!("day {} failed", 4)), "day 4 failed");
+        assert_eq!(message(|| panic::panic_any(4)), "the solver panicked");
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(AocError::new("empty input").to_string(), "empty input");
+        assert_eq!(
+            AocError::new("expected \"x,y -> x,y\"")
+                .at_line(17)
+                .in_file("data/day5.txt")
+                .to_string(),
+            "day5.txt:17: expected \"x,y -> x,y\""
+        );
+        assert_eq!(
+            AocError::new("bad digit")
+                .at_column(4)
+                .at_line(2)
+                .to_string(),
+            "2:4: bad digit"
+        );
+    }
+
+    #[test]
+    fn test_error_keeps_innermost_position() {
+        let err = AocError::new("bad").at_line(3).at_line(1);
+        assert_eq!(err.line, Some(3));
+    }
+
+    #[test]
+    fn test_load_str() {
+        assert_eq!(load_str(""), Vec::<String>::new());
+        assert_eq!(load_str("1\n2\n"), vec!["1", "2"]);
+        assert_eq!(load_str("1\n\n2"), vec!["1", "", "2"]);
+    }
+
+    #[test]
+    fn test_lines_from_reader() {
+        let input = "6,10\n0,14\n\nfold along y=7\n";
+        let lines = lines_from_reader(io::Cursor::new(input)).unwrap();
+
+        assert_eq!(lines, load_str(input));
+    }
+
+    #[test]
+    fn test_lines_from_reader_invalid_utf8() {
+        let input: &[u8] = b"ok\n\xff\n";
+        let err = lines_from_reader(input).unwrap_err();
+
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn test_normalise_crlf() {
+        let lines = load_str("6,10\r\n\r\nfold along y=7\r\n");
+
+        assert_eq!(lines, ["6,10", "", "fold along y=7"]);
+        assert_eq!(
+            check_normalised(&["6,10\r".to_owned()]).unwrap_err(),
+            AocError::new("ends with \\r, the input has Windows line endings")
+                .at_column(5)
+                .at_line(1)
+        );
+    }
+
+    #[test]
+    fn test_normalise_bom() {
+        assert_eq!(load_str("\u{feff}D2FE28\n"), ["D2FE28"]);
+        assert_eq!(load_str("\u{feff}"), Vec::<String>::new());
+
+        let err = check_normalised(&["\u{feff}D2FE28".to_owned()]).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), Some(1)));
+        assert_eq!(err.reason, "starts with a byte order mark");
+    }
+
+    #[test]
+    fn test_normalise_trailing_whitespace() {
+        assert_eq!(load_str("forward 5 \nup 3\t\n"), ["forward 5", "up 3"]);
+
+        let lines = ["forward 5".to_owned(), "up 3  ".to_owned()];
+        assert_eq!(
+            check_normalised(&lines).unwrap_err(),
+            AocError::new("has trailing whitespace")
+                .at_column(5)
+                .at_line(2)
+        );
+    }
+
+    #[test]
+    fn test_normalise_final_newline() {
+        assert_eq!(load_str("1\n2"), ["1", "2"]);
+        assert_eq!(load_str("1\n2\n\n\n"), ["1", "2"]);
+        assert_eq!(load_str("\n\n"), Vec::<String>::new());
+
+        let read = |input: &str| lines_from_reader(input.as_bytes()).unwrap();
+        assert_eq!(check_normalised(&read("1\n2")), Ok(()));
+        assert_eq!(check_normalised(&read("1\n2\n")), Ok(()));
+        assert_eq!(
+            check_normalised(&read("1\n2\n\n")).unwrap_err(),
+            AocError::new("blank line at the end of the input").at_line(3)
+        );
+        assert_eq!(check_normalised(&read("1\n\n2\n")), Ok(()));
+    }
+
+    #[test]
+    fn test_load_file_strict() {
+        let path = std::env::temp_dir().join(format!("aoc-strict-{}.txt", std::process::id()));
+        std::fs::write(&path, "3,4,3,1,2\r\n").unwrap();
+        let path = path.to_str().unwrap();
+
+        assert_eq!(load_file(path).unwrap(), ["3,4,3,1,2"]);
+        let err = load_file_strict(path).unwrap_err();
+        assert_eq!((err.file.as_deref(), err.line), (Some(path), Some(1)));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_solve_keeps_answers_of_parts_that_succeed() {
+        let example = load_str(include_str!("../../data/examples/day13.txt"));
+        let answers = solve::<days::day13::Day13>(&example, &[Part::A, Part::B]).unwrap();
+
+        assert_eq!(answers[0], (Part::A, Ok(Answer::Integer(17))));
+        assert_eq!(answers[1].0, Part::B);
+        assert_eq!(
+            answers[1].1.as_ref().unwrap_err().reason,
+            "expected letters 6 rows tall, found 7 rows"
+        );
+
+        assert!(solve::<days::day13::Day13>(&load_str("1,x"), &[Part::A]).is_err());
+    }
+
+    #[test]
+    fn test_load_file_missing() {
+        let err = load_file("data/no-such-day.txt").unwrap_err();
+        assert_eq!(err.file.as_deref(), Some("data/no-such-day.txt"));
+    }
+}
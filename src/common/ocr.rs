@@ -0,0 +1,140 @@
+use crate::AocError;
+
+pub const HEIGHT: usize = 6;
+pub const WIDTH: usize = 4;
+/// Letters are drawn four columns wide with one blank column after each.
+pub const PITCH: usize = WIDTH + 1;
+
+/// The block letters puzzles draw with `#` on a `.` background.
+pub const FONT: &[(char, [&str; HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters drawn in `picture`, whose rows use `#` for lit pixels. The first letter
+/// starts in the leftmost column; blank blocks are skipped.
+pub fn read_letters(picture: &[String]) -> Result<String, AocError> {
+    if picture.len() != HEIGHT {
+        return Err(AocError::new(format!(
+            "expected letters {} rows tall, found {} rows",
+            HEIGHT,
+            picture.len()
+        )));
+    }
+
+    let width = picture
+        .iter()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut letters = String::new();
+
+    for start in (0..width).step_by(PITCH) {
+        let block: Vec<String> = picture
+            .iter()
+            .map(|row| {
+                let mut pixels: String = row
+                    .chars()
+                    .skip(start)
+                    .take(WIDTH)
+                    .map(|c| if c == '#' { '#' } else { '.' })
+                    .collect();
+                while pixels.len() < WIDTH {
+                    pixels.push('.');
+                }
+                pixels
+            })
+            .collect();
+
+        if block.iter().all(|row| !row.contains('#')) {
+            continue;
+        }
+
+        let letter = FONT
+            .iter()
+            .find(|(_, glyph)| glyph.iter().eq(block.iter()))
+            .map(|&(letter, _)| letter)
+            .ok_or_else(|| {
+                AocError::new(format!(
+                    "unrecognised letter at column {}:\n{}",
+                    start + 1,
+                    block.join("\n")
+                ))
+            })?;
+
+        letters.push(letter);
+    }
+
+    if letters.is_empty() {
+        return Err(AocError::new("no letters in the picture"));
+    }
+
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load_str;
+
+    #[test]
+    fn test_read_letters() {
+        let picture = load_str(
+            "###..####.#..#.####.#....###...##..#..#.
+#..#....#.#.#.....#.#....#..#.#..#.#..#.
+#..#...#..##.....#..#....#..#.#....####.
+###...#...#.#...#...#....###..#.##.#..#.
+#.#..#....#.#..#....#....#....#..#.#..#.
+#..#.####.#..#.####.####.#.....###.#..#.",
+        );
+
+        assert_eq!(read_letters(&picture).unwrap(), "RZKZLPGH");
+    }
+
+    #[test]
+    fn test_read_whole_font() {
+        let picture: Vec<String> = (0..HEIGHT)
+            .map(|row| {
+                FONT.iter()
+                    .map(|(_, glyph)| glyph[row])
+                    .collect::<Vec<&str>>()
+                    .join(".")
+            })
+            .collect();
+        let alphabet: String = FONT.iter().map(|&(letter, _)| letter).collect();
+
+        assert_eq!(read_letters(&picture).unwrap(), alphabet);
+    }
+
+    #[test]
+    fn test_unrecognised_letter() {
+        let picture = load_str("#####\n#...#\n#...#\n#...#\n#...#\n#####");
+        let err = read_letters(&picture).unwrap_err();
+
+        assert_eq!(
+            err.reason,
+            "unrecognised letter at column 1:\n####\n#...\n#...\n#...\n#...\n####"
+        );
+    }
+
+    #[test]
+    fn test_wrong_height() {
+        assert!(read_letters(&load_str("####\n#..#")).is_err());
+        assert!(read_letters(&load_str("....\n....\n....\n....\n....\n....")).is_err());
+    }
+}
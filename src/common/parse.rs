@@ -0,0 +1,213 @@
+use std::str::FromStr;
+
+use crate::{parse_number, AocError};
+
+/// A run of non-blank lines, remembering where it starts in the input.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// 1-based line number of the first line in the section.
+    pub first_line: usize,
+    pub lines: &'a [String],
+}
+
+/// Returns the first line of the input, which is all that single-line puzzles need.
+pub fn first_line(lines: &[String]) -> Result<&str, AocError> {
+    lines
+        .first()
+        .map(|l| l.as_str())
+        .ok_or_else(|| AocError::new("empty input"))
+}
+
+/// Splits the input on blank lines. Leading, trailing and repeated blank lines are ignored.
+pub fn sections(lines: &[String]) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        match (line.is_empty(), start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                sections.push(Section {
+                    first_line: s + 1,
+                    lines: &lines[s..i],
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(s) = start {
+        sections.push(Section {
+            first_line: s + 1,
+            lines: &lines[s..],
+        });
+    }
+
+    sections
+}
+
+/// Parses a comma separated list of numbers such as `3,4,3,1,2`.
+pub fn csv_numbers<T: FromStr>(line: &str, expected: &str) -> Result<Vec<T>, AocError> {
+    let mut column = 1;
+
+    line.split(',')
+        .map(|item| {
+            let number = parse_number::<T>(item, expected).map_err(|e| e.at_column(column));
+            column += item.len() + 1;
+            number
+        })
+        .collect()
+}
+
+/// Parses numbers separated by any amount of whitespace, such as ` 8  2 23  4 24`.
+pub fn number_row<T: FromStr>(line: &str, expected: &str) -> Result<Vec<T>, AocError> {
+    line.split_whitespace()
+        .map(|item| {
+            let column = item.as_ptr() as usize - line.as_ptr() as usize + 1;
+            parse_number::<T>(item, expected).map_err(|e| e.at_column(column))
+        })
+        .collect()
+}
+
+/// Parses a rectangular grid of single digits in the given radix, such as rows of `0`/`1` bits.
+pub fn digit_grid(lines: &[String], radix: u32) -> Result<Vec<Vec<u8>>, AocError> {
+    grid(lines, |c| c.to_digit(radix).map(|d| d as u8), "a digit")
+}
+
+/// Parses a rectangular grid of characters.
+pub fn char_grid(lines: &[String]) -> Result<Vec<Vec<char>>, AocError> {
+    grid(lines, Some, "a character")
+}
+
+fn grid<T>(
+    lines: &[String],
+    cell: impl Fn(char) -> Option<T>,
+    expected: &str,
+) -> Result<Vec<Vec<T>>, AocError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(column, c)| {
+                cell(c).ok_or_else(|| {
+                    AocError::new(format!("expected {}, found {:?}", expected, c))
+                        .at_column(column + 1)
+                        .at_line(i + 1)
+                })
+            })
+            .collect::<Result<Vec<T>, AocError>>()?;
+
+        if row.is_empty() {
+            return Err(AocError::new("expected a row of the grid").at_line(i + 1));
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(AocError::new(format!(
+                    "expected {} columns, found {}",
+                    first.len(),
+                    row.len()
+                ))
+                .at_line(i + 1));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return Err(AocError::new("empty input"));
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load_str;
+
+    #[test]
+    fn test_first_line() {
+        assert_eq!(first_line(&load_str("3,4\n5")).unwrap(), "3,4");
+        assert_eq!(first_line(&[]).unwrap_err().reason, "empty input");
+    }
+
+    #[test]
+    fn test_sections() {
+        let lines = load_str("7,4,9\n\n22 13\n 8  2\n\n\n3 15\n");
+        let sections = sections(&lines);
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].first_line, 1);
+        assert_eq!(sections[0].lines, &["7,4,9"]);
+        assert_eq!(sections[1].first_line, 3);
+        assert_eq!(sections[1].lines, &["22 13", " 8  2"]);
+        assert_eq!(sections[2].first_line, 7);
+        assert_eq!(sections[2].lines, &["3 15"]);
+    }
+
+    #[test]
+    fn test_sections_leading_blank_lines() {
+        let lines = load_str("\n\n1\n2");
+        let sections = sections(&lines);
+
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].first_line, 3);
+        assert!(super::sections(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_csv_numbers() {
+        assert_eq!(
+            csv_numbers::<u8>("3,4,3,1,2", "a timer").unwrap(),
+            vec![3, 4, 3, 1, 2]
+        );
+
+        let err = csv_numbers::<u8>("3,40,x", "a timer").unwrap_err();
+        assert_eq!(err.column, Some(6));
+        assert_eq!(err.reason, "expected a timer, found \"x\"");
+
+        assert!(csv_numbers::<u8>("", "a timer").is_err());
+        assert!(csv_numbers::<u8>("1,,2", "a timer").is_err());
+    }
+
+    #[test]
+    fn test_number_row() {
+        assert_eq!(
+            number_row::<u64>(" 1 20 99  2", "n").unwrap(),
+            vec![1, 20, 99, 2]
+        );
+        assert_eq!(number_row::<u64>("", "n").unwrap(), Vec::<u64>::new());
+        assert_eq!(number_row::<u64>(" 1  x", "n").unwrap_err().column, Some(5));
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        let lines = load_str("00100\n11110");
+        assert_eq!(
+            digit_grid(&lines, 2).unwrap(),
+            vec![vec![0, 0, 1, 0, 0], vec![1, 1, 1, 1, 0]]
+        );
+
+        let err = digit_grid(&load_str("001\n021"), 2).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+
+        let err = digit_grid(&load_str("001\n01"), 2).unwrap_err();
+        assert_eq!(err.line, Some(2));
+
+        assert!(digit_grid(&[], 10).is_err());
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let lines = load_str("#.\n.#");
+        assert_eq!(
+            char_grid(&lines).unwrap(),
+            vec![vec!['#', '.'], vec!['.', '#']]
+        );
+        assert!(char_grid(&load_str("#.\n\n.#")).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::path::Path;
+use std::process;
+
+use common::differential::Disagreement;
+use common::rng::Rng;
+use common::{load_file, AocError};
+
+use crate::batch::{input_dir, list_inputs};
+use crate::{default_input, find_day, usage, Day, DAYS};
+
+const DEFAULT_RUNS: u64 = 100;
+const DEFAULT_SIZE: usize = 100;
+
+/// Checks each day's alternative implementations against the main ones on its real inputs and
+/// on generated ones, and prints the smallest input found for any disagreement.
+pub fn differential(args: &[String]) {
+    let mut target = None;
+    let mut runs = DEFAULT_RUNS;
+    let mut max_size = DEFAULT_SIZE;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                runs = match args.next().map(|s| s.parse::<u64>()) {
+                    Some(Ok(runs)) => runs,
+                    _ => usage(),
+                }
+            }
+            "--size" => {
+                max_size = match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(size)) if size > 0 => size,
+                    _ => usage(),
+                }
+            }
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ => usage(),
+        }
+    }
+
+    let days: Vec<&Day> = match target {
+        Some("all") => DAYS
+            .iter()
+            .filter(|day| !(day.alternatives)().is_empty())
+            .collect(),
+        Some(number) => match number.parse::<u32>().ok().and_then(find_day) {
+            Some(day) if !(day.alternatives)().is_empty() => vec![day],
+            Some(day) => {
+                println!("Day {} has no alternative implementations", day.number);
+                process::exit(1);
+            }
+            None => {
+                println!("No solution registered for day {}", number);
+                process::exit(1);
+            }
+        },
+        None => usage(),
+    };
+
+    let mut failed = false;
+    for day in days {
+        let names: Vec<String> = (day.alternatives)()
+            .iter()
+            .map(|(part, name)| format!("{} (part {})", name, part))
+            .collect();
+
+        match check_day(day, runs, max_size) {
+            Ok(checked) => println!(
+                "Day {}: {} agreed on {} inputs",
+                day.number,
+                names.join(", "),
+                checked
+            ),
+            Err(report) => {
+                println!("Day {}: {}", day.number, report);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Checks a day on its input and those in `data/dayN/`, then on `runs` generated inputs of up
+/// to `max_size` items, returning how many inputs agreed or a report of the first that did not.
+fn check_day(day: &Day, runs: u64, max_size: usize) -> Result<usize, String> {
+    let mut files = Vec::new();
+    let input = default_input(day.number);
+    if Path::new(&input).is_file() {
+        files.push(input);
+    }
+    if let Ok(inputs) = list_inputs(&input_dir(day.number)) {
+        files.extend(inputs);
+    }
+
+    for file in &files {
+        let lines = load_file(file).map_err(|e| e.to_string())?;
+        report(file, (day.differ)(&lines).map_err(|e| e.in_file(file)))?;
+    }
+
+    for seed in 0..runs {
+        let size = 1 + seed as usize % max_size;
+        let lines = (day.generate)(&mut Rng::new(seed), size);
+        let source = format!("generated input (seed {}, size {})", seed, size);
+        report(&source, (day.differ)(&lines))?;
+    }
+
+    Ok(files.len() + runs as usize)
+}
+
+fn report(source: &str, outcome: Result<Option<Disagreement>, AocError>) -> Result<(), String> {
+    match outcome {
+        Ok(None) => Ok(()),
+        Ok(Some(disagreement)) => Err(format!("{}\n{}", source, disagreement)),
+        Err(err) => Err(format!("{}: {}", source, err)),
+    }
+}
@@ -0,0 +1,50 @@
+use std::process;
+
+use common::rng::Rng;
+
+use crate::{find_day, usage};
+
+const DEFAULT_SEED: u64 = 2021;
+const DEFAULT_SIZE: usize = 1000;
+
+/// Prints a random input for a day, the same for the same seed and size.
+pub fn generate(args: &[String]) {
+    let mut target = None;
+    let mut seed = DEFAULT_SEED;
+    let mut size = DEFAULT_SIZE;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                seed = match args.next().map(|s| s.parse::<u64>()) {
+                    Some(Ok(seed)) => seed,
+                    _ => usage(),
+                }
+            }
+            "--size" => {
+                size = match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(size)) if size > 0 => size,
+                    _ => usage(),
+                }
+            }
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ => usage(),
+        }
+    }
+
+    let day = match target {
+        Some(number) => match number.parse::<u32>().ok().and_then(find_day) {
+            Some(day) => day,
+            None => {
+                println!("No solution registered for day {}", number);
+                process::exit(1);
+            }
+        },
+        None => usage(),
+    };
+
+    for line in (day.generate)(&mut Rng::new(seed), size) {
+        println!("{}", line);
+    }
+}
@@ -0,0 +1,121 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use common::days::day13::{self, Day13};
+use common::days::day17::{self, Day17};
+use common::days::day5::{self, Day5};
+use common::geometry::Point;
+use common::image::{pgm, ppm, upscale};
+use common::{load_file, AocError, Solution};
+
+use crate::{default_input, usage};
+
+/// How many of day 17's hitting shots are drawn besides the highest one.
+const SHOTS: usize = 60;
+
+/// Writes pictures of a day's model: day 5's vent heatmaps, day 13's paper before and after
+/// each fold, and day 17's shots at the target.
+pub fn images(args: &[String]) {
+    let mut target = None;
+    let mut out = PathBuf::from(".");
+    let mut scale = 1;
+    let mut file = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => {
+                out = match args.next() {
+                    Some(dir) => PathBuf::from(dir),
+                    None => usage(),
+                }
+            }
+            "--scale" => {
+                scale = match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(scale)) if scale > 0 => scale,
+                    _ => usage(),
+                }
+            }
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ if file.is_none() => file = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+
+    let number = match target.map(|number| number.parse::<u32>()) {
+        Some(Ok(number)) => number,
+        _ => usage(),
+    };
+    let file = file.unwrap_or_else(|| default_input(number));
+
+    let images = match draw(number, &file, scale) {
+        Ok(Some(images)) => images,
+        Ok(None) => {
+            println!("No pictures for day {}, try day 5, 13 or 17", number);
+            process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+
+    if let Err(err) = write(&out, &images) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+/// A picture's file name and contents.
+type Picture = (String, Vec<u8>);
+
+/// The pictures of a day, or `None` for a day without any.
+fn draw(number: u32, file: &str, scale: usize) -> Result<Option<Vec<Picture>>, AocError> {
+    let lines = load_file(file)?;
+    let in_file = |e: AocError| e.in_file(file);
+
+    let images = match number {
+        5 => {
+            let lines = Day5::parse(&lines).map_err(in_file)?;
+            [("a", false), ("b", true)]
+                .iter()
+                .map(|&(part, diagonal)| {
+                    let heatmap = day5::heatmap(&day5::layout_lines(&lines, diagonal));
+                    (format!("day5-{}.ppm", part), ppm(&upscale(&heatmap, scale)))
+                })
+                .collect()
+        }
+        13 => {
+            let manual = Day13::parse(&lines).map_err(in_file)?;
+            day13::fold_stages(&manual.positions, &manual.folds)
+                .iter()
+                .enumerate()
+                .map(|(folds, sheet)| {
+                    let image = upscale(&sheet.image(), scale);
+                    (format!("day13-{:02}.pgm", folds), pgm(&image))
+                })
+                .collect()
+        }
+        17 => {
+            let target = Day17::parse(&lines).map_err(in_file)?;
+            let svg = day17::draw_trajectories(Point::ORIGIN, &target, SHOTS, scale as f64);
+            vec![("day17.svg".to_owned(), svg.to_string().into_bytes())]
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(images))
+}
+
+fn write(out: &Path, images: &[Picture]) -> Result<(), String> {
+    fs::create_dir_all(out).map_err(|e| format!("{}: {}", out.display(), e))?;
+
+    for (name, contents) in images {
+        let path = out.join(name);
+        fs::write(&path, contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,519 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use common::days::day13::{self, Day13, Fold, Manual};
+use common::days::day16::{self, Day16, Packet, PacketContent, PacketType};
+use common::days::day5::{self, Day5};
+use common::days::day6::{self, Day6};
+use common::geometry::Point;
+use common::grid::{Grid, SparseGrid};
+use common::{load_file, Solution};
+
+use crate::{default_input, usage};
+
+const HELP: &str = "commands:
+    load <day> [<file>]   parse a day's input, by default its puzzle input
+    day5 at <x>,<y>       how many vent lines cover a point
+    day5 dangerous        how many points the vent lines overlap on
+    day6 simulate <days>  the fish after some days, by timer
+    day13 fold x|y=<n>    fold the paper along a line and show it
+    day13 next            apply the input's next fold
+    day13 reset           unfold the paper
+    day13 show            show the paper
+    day16 eval            evaluate the packet, showing every subpacket's value
+    day16 versions        the sum of the packet's version numbers
+    help
+    quit";
+
+/// Paper larger than this is described rather than drawn.
+const MAX_DRAWN: (u64, u64) = (80, 40);
+
+/// The layouts of the vent lines, without and with the diagonal ones.
+struct Vents {
+    lines: usize,
+    straight: SparseGrid<usize>,
+    all: SparseGrid<usize>,
+}
+
+/// The transparent paper as folded so far.
+struct Paper {
+    manual: Manual,
+    dots: SparseGrid<()>,
+    folded: Vec<Fold>,
+}
+
+/// The models parsed so far, one per day the commands explore.
+#[derive(Default)]
+struct Session {
+    vents: Option<Vents>,
+    fish: Option<HashMap<u8, usize>>,
+    paper: Option<Paper>,
+    packet: Option<Packet>,
+}
+
+/// Reads commands from stdin and prints what they show, until `quit` or the end of input.
+pub fn repl(args: &[String]) {
+    if !args.is_empty() {
+        usage();
+    }
+
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let mut session = Session::default();
+
+    loop {
+        if interactive {
+            print!("> ");
+            io::stdout().flush().unwrap();
+        }
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("{}", err);
+                break;
+            }
+        }
+
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" => break,
+            command => match execute(&mut session, command) {
+                Ok(output) => {
+                    for line in output {
+                        println!("{}", line);
+                    }
+                }
+                Err(err) => println!("error: {}", err),
+            },
+        }
+    }
+}
+
+/// Runs one command, loading a day's puzzle input first if nothing was loaded for it yet.
+fn execute(session: &mut Session, command: &str) -> Result<Vec<String>, String> {
+    let words: Vec<&str> = command.split_whitespace().collect();
+
+    let (number, rest) = match words.as_slice() {
+        ["help"] => return Ok(HELP.lines().map(|l| l.to_owned()).collect()),
+        ["load", number] => {
+            let number = parse_day(number)?;
+            return load(session, number, &default_input(number));
+        }
+        ["load", number, file] => return load(session, parse_day(number)?, file),
+        [day, rest @ ..] => match day.strip_prefix("day") {
+            Some(number) => (parse_day(number)?, rest),
+            None => return Err(format!("unknown command {:?}, try help", day)),
+        },
+        [] => return Ok(Vec::new()),
+    };
+
+    let mut output = Vec::new();
+    if !session.is_loaded(number) {
+        let file = default_input(number);
+        output.extend(load(session, number, &file)?);
+    }
+    output.extend(session.run(number, rest)?);
+    Ok(output)
+}
+
+fn parse_day(number: &str) -> Result<u32, String> {
+    number
+        .parse::<u32>()
+        .map_err(|_| format!("expected a day, found {:?}", number))
+}
+
+fn load(session: &mut Session, number: u32, file: &str) -> Result<Vec<String>, String> {
+    let lines = load_file(file).map_err(|e| e.to_string())?;
+    let parse_error = |e: common::AocError| e.in_file(file).to_string();
+
+    let loaded = match number {
+        5 => {
+            let lines = Day5::parse(&lines).map_err(parse_error)?;
+            let vents = Vents {
+                lines: lines.len(),
+                straight: day5::layout_lines(&lines, false),
+                all: day5::layout_lines(&lines, true),
+            };
+            let loaded = format!("{} vent lines", vents.lines);
+            session.vents = Some(vents);
+            loaded
+        }
+        6 => {
+            let fish = Day6::parse(&lines).map_err(parse_error)?;
+            let loaded = format!("{} fish", fish.values().sum::<usize>());
+            session.fish = Some(fish);
+            loaded
+        }
+        13 => {
+            let manual = Day13::parse(&lines).map_err(parse_error)?;
+            let loaded = format!(
+                "{} dots and {} folds",
+                manual.positions.len(),
+                manual.folds.len()
+            );
+            session.paper = Some(Paper {
+                dots: manual.positions.clone(),
+                manual,
+                folded: Vec::new(),
+            });
+            loaded
+        }
+        16 => {
+            let packet = Day16::parse(&lines).map_err(parse_error)?;
+            let loaded = format!("a packet of {} packets", count_packets(&packet));
+            session.packet = Some(packet);
+            loaded
+        }
+        _ => return Err(no_commands(number)),
+    };
+
+    Ok(vec![format!("loaded {} from {}", loaded, file)])
+}
+
+fn no_commands(number: u32) -> String {
+    format!("day {} has no commands, try day 5, 6, 13 or 16", number)
+}
+
+impl Session {
+    fn is_loaded(&self, number: u32) -> bool {
+        match number {
+            5 => self.vents.is_some(),
+            6 => self.fish.is_some(),
+            13 => self.paper.is_some(),
+            16 => self.packet.is_some(),
+            _ => false,
+        }
+    }
+
+    fn run(&mut self, number: u32, command: &[&str]) -> Result<Vec<String>, String> {
+        match (number, command) {
+            (5, ["at", point]) => {
+                let vents = self.vents.as_ref().unwrap();
+                let point = parse_point(point)?;
+                let covering = |layout: &SparseGrid<usize>| *layout.get(point).unwrap_or(&0);
+                Ok(vec![format!(
+                    "{},{}: {} vent lines, {} with diagonals",
+                    point.x,
+                    point.y,
+                    covering(&vents.straight),
+                    covering(&vents.all)
+                )])
+            }
+            (5, ["dangerous"]) => {
+                let vents = self.vents.as_ref().unwrap();
+                Ok(vec![format!(
+                    "{} vent lines overlap on {} points, {} with diagonals",
+                    vents.lines,
+                    day5::count_dangerous_spots(&vents.straight),
+                    day5::count_dangerous_spots(&vents.all)
+                )])
+            }
+            (6, ["simulate", days]) => {
+                let days = days
+                    .parse::<usize>()
+                    .map_err(|_| format!("expected a number of days, found {:?}", days))?;
+                let too_many = |day| format!("the number of fish overflows on day {}", day);
+                let mut fish = self.fish.clone().unwrap();
+                for day in 1..=days {
+                    fish = day6::next_day(fish).ok_or_else(|| too_many(day))?;
+                }
+                let total = day6::count(&fish).ok_or_else(|| too_many(days))?;
+                let timers: Vec<String> = (0..=8)
+                    .map(|timer| format!("{}:{}", timer, fish.get(&timer).unwrap_or(&0)))
+                    .collect();
+                Ok(vec![
+                    format!("after {} days: {} fish", days, total),
+                    format!("by timer: {}", timers.join(" ")),
+                ])
+            }
+            (13, ["fold", line]) => {
+                let fold = parse_fold(line)?;
+                self.paper.as_mut().unwrap().fold(fold)
+            }
+            (13, ["next"]) => {
+                let paper = self.paper.as_mut().unwrap();
+                match paper.manual.folds.get(paper.folded.len()).copied() {
+                    Some(fold) => paper.fold(fold),
+                    None => Err("every fold from the input is done".to_owned()),
+                }
+            }
+            (13, ["reset"]) => {
+                let paper = self.paper.as_mut().unwrap();
+                paper.dots = paper.manual.positions.clone();
+                paper.folded.clear();
+                Ok(paper.show())
+            }
+            (13, ["show"]) => Ok(self.paper.as_ref().unwrap().show()),
+            (16, ["eval"]) => {
+                let packet = self.packet.as_ref().unwrap();
+                let mut output = Vec::new();
+                describe_packet(packet, 0, &mut output);
+                Ok(output)
+            }
+            (16, ["versions"]) => Ok(vec![format!(
+                "version sum: {}",
+                day16::sum_packet_versions(self.packet.as_ref().unwrap())
+            )]),
+            (5 | 6 | 13 | 16, _) => Err(format!(
+                "unknown command for day {}: {:?}, try help",
+                number,
+                command.join(" ")
+            )),
+            _ => Err(no_commands(number)),
+        }
+    }
+}
+
+impl Paper {
+    /// Folds the paper, unless the fold line does not cross it as folded so far.
+    fn fold(&mut self, fold: Fold) -> Result<Vec<String>, String> {
+        let size = day13::folded_size(&self.manual.positions, &self.folded);
+        day13::check_fold(fold, size).map_err(|e| e.to_string())?;
+
+        self.dots = day13::fold(&self.dots, fold);
+        self.folded.push(fold);
+        Ok(self.show())
+    }
+
+    fn show(&self) -> Vec<String> {
+        let folds: Vec<String> = self.folded.iter().map(|fold| fold.to_string()).collect();
+        let mut output = vec![format!(
+            "{} dots after {} folds{}",
+            self.dots.len(),
+            folds.len(),
+            if folds.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", folds.join(", "))
+            }
+        )];
+
+        if let Some(area) = self.dots.bounding_box() {
+            // folds short of the middle leave dots left of or above the paper
+            let paper = area.including(Point::ORIGIN);
+            if paper.width() <= MAX_DRAWN.0 && paper.height() <= MAX_DRAWN.1 {
+                output.extend(self.dots.render_area(paper, |dot| match dot {
+                    Some(()) => '#',
+                    None => '.',
+                }));
+            } else {
+                output.push(format!(
+                    "({}x{}, too large to draw)",
+                    paper.width(),
+                    paper.height()
+                ));
+            }
+        }
+        output
+    }
+}
+
+fn parse_point(point: &str) -> Result<Point, String> {
+    let coordinates = point
+        .split_once(',')
+        .and_then(|(x, y)| Some(Point::new(x.parse().ok()?, y.parse().ok()?)));
+    coordinates.ok_or_else(|| format!("expected x,y, found {:?}", point))
+}
+
+fn parse_fold(line: &str) -> Result<Fold, String> {
+    match line.split_once('=') {
+        Some((axis @ ("x" | "y"), n)) => day13::fold_line(axis, n).map_err(|e| e.to_string()),
+        _ => Err(format!("expected x=N or y=N, found {:?}", line)),
+    }
+}
+
+fn count_packets(packet: &Packet) -> usize {
+    match &packet.content {
+        PacketContent::Literal { .. } => 1,
+        PacketContent::Operator { subpackets } => {
+            1 + subpackets.iter().map(count_packets).sum::<usize>()
+        }
+    }
+}
+
+/// Writes a packet and its subpackets as an indented tree with the value of each.
+fn describe_packet(packet: &Packet, depth: usize, output: &mut Vec<String>) {
+    let operation = match packet.packet_type {
+        PacketType::Sum => "sum",
+        PacketType::Product => "product",
+        PacketType::Min => "min",
+        PacketType::Max => "max",
+        PacketType::Literal => "literal",
+        PacketType::GreaterThan => "greater than",
+        PacketType::LessThan => "less than",
+        PacketType::EqualTo => "equal to",
+    };
+    let value = match day16::evaluate(packet) {
+        Ok(value) => value.to_string(),
+        Err(err) => format!("error ({})", err),
+    };
+    output.push(format!(
+        "{}{} = {} (version {})",
+        "  ".repeat(depth),
+        operation,
+        value,
+        packet.version
+    ));
+
+    if let PacketContent::Operator { subpackets } = &packet.content {
+        for subpacket in subpackets {
+            describe_packet(subpacket, depth + 1, output);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(session: &mut Session, command: &str) -> Vec<String> {
+        execute(session, command).unwrap()
+    }
+
+    #[test]
+    fn test_vents() {
+        let mut session = Session::default();
+        run(&mut session, "load 5 data/examples/day5.txt");
+
+        assert_eq!(
+            run(&mut session, "day5 at 3,4"),
+            ["3,4: 2 vent lines, 2 with diagonals"]
+        );
+        assert_eq!(
+            run(&mut session, "day5 at 1,1"),
+            ["1,1: 0 vent lines, 1 with diagonals"]
+        );
+        assert_eq!(
+            run(&mut session, "day5 at 0,9"),
+            ["0,9: 2 vent lines, 2 with diagonals"]
+        );
+        assert!(execute(&mut session, "day5 at 3").is_err());
+    }
+
+    #[test]
+    fn test_fish() {
+        let mut session = Session::default();
+        run(&mut session, "load 6 data/examples/day6.txt");
+
+        assert_eq!(
+            run(&mut session, "day6 simulate 18"),
+            [
+                "after 18 days: 26 fish",
+                "by timer: 0:3 1:5 2:3 3:2 4:2 5:1 6:5 7:1 8:4"
+            ]
+        );
+        assert_eq!(
+            run(&mut session, "day6 simulate 80")[0],
+            "after 80 days: 5934 fish"
+        );
+        assert_eq!(
+            execute(&mut session, "day6 simulate 1000000000000"),
+            Err("the number of fish overflows on day 512".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_paper() {
+        let mut session = Session::default();
+        run(&mut session, "load 13 data/examples/day13.txt");
+
+        assert_eq!(
+            run(&mut session, "day13 fold y=7")[0],
+            "17 dots after 1 folds (y=7)"
+        );
+        assert_eq!(
+            run(&mut session, "day13 fold x=5"),
+            [
+                "16 dots after 2 folds (y=7, x=5)",
+                "#####",
+                "#...#",
+                "#...#",
+                "#...#",
+                "#####"
+            ]
+        );
+        assert!(execute(&mut session, "day13 next").is_err());
+        assert_eq!(run(&mut session, "day13 reset")[0], "18 dots after 0 folds");
+        assert_eq!(
+            run(&mut session, "day13 next")[0],
+            "17 dots after 1 folds (y=7)"
+        );
+    }
+
+    #[test]
+    fn test_packet() {
+        let mut session = Session::default();
+        run(&mut session, "load 16 data/examples/day16-b.txt");
+
+        assert_eq!(
+            run(&mut session, "day16 eval"),
+            [
+                "equal to = 1 (version 4)",
+                "  sum = 4 (version 2)",
+                "    literal = 1 (version 2)",
+                "    literal = 3 (version 4)",
+                "  product = 4 (version 6)",
+                "    literal = 2 (version 0)",
+                "    literal = 2 (version 2)"
+            ]
+        );
+        assert_eq!(run(&mut session, "day16 versions"), ["version sum: 20"]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut session = Session::default();
+
+        assert!(execute(&mut session, "frobnicate").is_err());
+        assert!(execute(&mut session, "day7 eval").is_err());
+        assert!(execute(&mut session, "load 5 data/missing.txt").is_err());
+        assert!(execute(&mut session, "load 13 data/examples/day13.txt").is_ok());
+        assert!(execute(&mut session, "day13 fold z=3").is_err());
+        assert!(execute(&mut session, "day13 twist").is_err());
+    }
+
+    #[test]
+    fn test_fold_errors() {
+        let mut session = Session::default();
+        run(&mut session, "load 13 data/examples/day13.txt");
+
+        assert_eq!(
+            execute(&mut session, "day13 fold x=-9223372036854775808"),
+            Err("expected a non-negative fold line, found \"-9223372036854775808\"".to_owned())
+        );
+        assert_eq!(
+            execute(&mut session, "day13 fold x=-3"),
+            Err("expected a non-negative fold line, found \"-3\"".to_owned())
+        );
+        assert_eq!(
+            execute(&mut session, "day13 fold x=0"),
+            Err(
+                "expected a fold line across the paper, which is 11 columns wide, found x=0"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            execute(&mut session, "day13 fold y=15"),
+            Err(
+                "expected a fold line across the paper, which is 15 rows tall, found y=15"
+                    .to_owned()
+            )
+        );
+
+        run(&mut session, "day13 fold y=7");
+        assert!(execute(&mut session, "day13 fold y=7").is_err());
+        assert_eq!(
+            run(&mut session, "day13 fold x=3")[0],
+            "14 dots after 2 folds (y=7, x=3)"
+        );
+        // dots folded past the left edge are still drawn
+        let drawn: usize = run(&mut session, "day13 show")[1..]
+            .iter()
+            .map(|row| row.matches('#').count())
+            .sum();
+        assert_eq!(drawn, 14);
+    }
+}
@@ -0,0 +1,279 @@
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use crate::{default_input, find_day, usage};
+
+const DAYS_DIR: &str = "src/common/days";
+const DAYS_MOD: &str = "src/common/days/mod.rs";
+const EXAMPLES: &str = "data/examples";
+
+/// Adds a new day: its solution skeleton with tests, empty input and example files, and its
+/// module and entry in the library's `DAYS`. Nothing is written if any of the files exists.
+pub fn new_day(args: &[String]) {
+    let number = match args {
+        [number] => match number.parse::<u32>() {
+            Ok(number) if (1..=25).contains(&number) => number,
+            _ => usage(),
+        },
+        _ => usage(),
+    };
+
+    if find_day(number).is_some() {
+        println!("Day {} is already registered", number);
+        process::exit(1);
+    }
+
+    if let Err(err) = scaffold(number) {
+        println!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn scaffold(number: u32) -> Result<(), String> {
+    let source = format!("{}/day{}.rs", DAYS_DIR, number);
+    let input = default_input(number);
+    let example = format!("{}/day{}.txt", EXAMPLES, number);
+
+    let existing: Vec<&str> = [&source, &input, &example]
+        .iter()
+        .map(|path| path.as_str())
+        .filter(|path| Path::new(path).exists())
+        .collect();
+    if !existing.is_empty() {
+        return Err(format!("refusing to overwrite {}", existing.join(", ")));
+    }
+
+    let days_mod = register_module(&read(DAYS_MOD)?, number)?;
+    let days_mod = register(
+        &days_mod,
+        DAYS_MOD,
+        "pub const DAYS",
+        &format!("    day::<day{n}::Day{n}>({n}),", n = number),
+        number,
+    )?;
+
+    write(&source, &skeleton(number))?;
+    write(&input, "")?;
+    write(&example, "")?;
+    write(DAYS_MOD, &days_mod)?;
+
+    for path in [&source, &input, &example] {
+        println!("created {}", path);
+    }
+    println!("updated {}", DAYS_MOD);
+
+    Ok(())
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn write(path: &str, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("{}: {}", path, e))
+}
+
+/// The source of a day that parses its input as lines and answers with their count, with a test
+/// of the example waiting for the puzzle's answers.
+fn skeleton(number: u32) -> String {
+    format!(
+        r#"use crate::rng::Rng;
+use crate::{{AocError, Solution}};
+
+pub struct Day{n};
+
+impl Solution for Day{n} {{
+    type Input = Vec<String>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(lines: &[String]) -> Result<Vec<String>, AocError> {{
+        Ok(lines.to_vec())
+    }}
+
+    fn part_a(lines: &Vec<String>) -> usize {{
+        lines.len()
+    }}
+
+    fn part_b(lines: &Vec<String>) -> usize {{
+        lines.len()
+    }}
+
+    fn generate(rng: &mut Rng, size: usize) -> Vec<String> {{
+        (0..size).map(|_| rng.below(100).to_string()).collect()
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::fuzz::{{check_generator, check_parser}};
+    use crate::load_str;
+
+    #[test]
+    fn test_example() {{
+        let example = load_str(include_str!("../../../data/examples/day{n}.txt"));
+        let input = Day{n}::parse(&example).unwrap();
+
+        // the answers given for the example in the puzzle
+        assert_eq!(Day{n}::part_a(&input), 0);
+        assert_eq!(Day{n}::part_b(&input), 0);
+    }}
+
+    #[test]
+    fn test_parse_never_panics() {{
+        check_parser::<Day{n}>(&[
+            include_str!("../../../data/examples/day{n}.txt"),
+            include_str!("../../../data/day{n}.txt"),
+        ]);
+    }}
+
+    #[test]
+    fn test_generated_inputs_solve() {{
+        check_generator::<Day{n}>(&[1, 10, 100]);
+    }}
+}}
+"#,
+        n = number
+    )
+}
+
+/// Adds `pub mod dayN;` to the days module, keeping the modules in alphabetical order.
+fn register_module(days_mod: &str, number: u32) -> Result<String, String> {
+    let name = format!("day{}", number);
+    let declaration = format!("pub mod {};", name);
+    let mut lines: Vec<&str> = days_mod.lines().collect();
+
+    if lines.contains(&declaration.as_str()) {
+        return Err(format!("{} already declares {}", DAYS_MOD, name));
+    }
+
+    let at = match lines
+        .iter()
+        .position(|line| module_name(line).is_some_and(|module| module > name.as_str()))
+    {
+        // the next module's doc comment stays with it
+        Some(mut at) => {
+            while at > 0 && lines[at - 1].starts_with("///") {
+                at -= 1;
+            }
+            at
+        }
+        None => lines
+            .iter()
+            .rposition(|line| module_name(line).is_some())
+            .map_or(lines.len(), |last| last + 1),
+    };
+
+    let doc = format!("/// Day {}.", number);
+    lines.splice(at..at, [doc.as_str(), declaration.as_str()]);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The module a `pub mod` line declares.
+fn module_name(line: &str) -> Option<&str> {
+    line.strip_prefix("pub mod ")?.strip_suffix(';')
+}
+
+/// Adds `entry` for a day to the table starting with `table` in `source`, after the last day
+/// with a lower number. Entries name their day's type, such as `Day17`.
+fn register(
+    source: &str,
+    path: &str,
+    table: &str,
+    entry: &str,
+    number: u32,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(table))
+        .ok_or_else(|| format!("{} has no `{}` table", path, table))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or_else(|| format!("{} has an unterminated `{}` table", path, table))?;
+
+    let registered = |line: &str| {
+        let (_, rest) = line.split_once("::Day")?;
+        let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse::<u32>().ok()
+    };
+    if lines[start + 1..end]
+        .iter()
+        .any(|line| registered(line) == Some(number))
+    {
+        return Err(format!("{} already registers day {}", path, number));
+    }
+
+    let at = (start + 1..end)
+        .find(|&i| registered(lines[i]).is_some_and(|n| n > number))
+        .unwrap_or(end);
+    lines.insert(at, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_module() {
+        let days_mod = "/// One.\npub mod day1;\n/// Ten.\npub mod day10;\n/// Two.\npub mod day2;\n\nconst X: u32 = 1;\n";
+
+        assert_eq!(
+            register_module(days_mod, 18).unwrap(),
+            "/// One.\npub mod day1;\n/// Ten.\npub mod day10;\n/// Day 18.\npub mod day18;\n/// Two.\npub mod day2;\n\nconst X: u32 = 1;\n"
+        );
+        assert_eq!(
+            register_module(days_mod, 3).unwrap(),
+            "/// One.\npub mod day1;\n/// Ten.\npub mod day10;\n/// Two.\npub mod day2;\n/// Day 3.\npub mod day3;\n\nconst X: u32 = 1;\n"
+        );
+        assert!(register_module(days_mod, 10).is_err());
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = "pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(1),
+    day::<day17::Day17>(17),
+];
+";
+        let day = |n: u32| format!("    day::<day{n}::Day{n}>({n}),", n = n);
+
+        assert_eq!(
+            register(registry, "mod.rs", "pub const DAYS", &day(5), 5).unwrap(),
+            "pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(1),
+    day::<day5::Day5>(5),
+    day::<day17::Day17>(17),
+];
+"
+        );
+        assert_eq!(
+            register(registry, "mod.rs", "pub const DAYS", &day(18), 18).unwrap(),
+            "pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(1),
+    day::<day17::Day17>(17),
+    day::<day18::Day18>(18),
+];
+"
+        );
+        assert!(register(registry, "mod.rs", "pub const DAYS", &day(17), 17).is_err());
+        assert!(register("fn main() {}\n", "mod.rs", "pub const DAYS", &day(18), 18).is_err());
+    }
+
+    #[test]
+    fn test_skeleton() {
+        let source = skeleton(18);
+
+        assert!(source.contains("pub struct Day18;"));
+        assert!(source.contains("impl Solution for Day18 {"));
+        assert!(source.contains("include_str!(\"../../../data/day18.txt\")"));
+    }
+}
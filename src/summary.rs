@@ -0,0 +1,149 @@
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::bench::TimedAnswers;
+use common::manifest::escape_answer;
+use common::{load_file, panic_message, AocError, Part};
+
+use crate::{default_input, usage, Day, DAYS};
+
+struct Row {
+    day: u32,
+    part: Option<Part>,
+    answer: String,
+    time: Option<Duration>,
+    status: &'static str,
+}
+
+/// Solves every registered day on its own thread and prints one table once all have finished,
+/// in day order whichever finishes first.
+pub fn summary(args: &[String]) {
+    let parts = match args {
+        [] => vec![Part::A, Part::B],
+        [flag, part] if flag == "--part" => match part.parse::<Part>() {
+            Ok(part) => vec![part],
+            Err(_) => usage(),
+        },
+        _ => usage(),
+    };
+
+    let start = Instant::now();
+
+    let handles: Vec<_> = DAYS
+        .iter()
+        .map(|day| {
+            let parts = parts.clone();
+            (day.number, thread::spawn(move || solve_day(day, &parts)))
+        })
+        .collect();
+
+    let mut rows = Vec::new();
+    for (number, handle) in handles {
+        match handle.join() {
+            Ok(Ok(answers)) => rows.extend(answers.into_iter().map(|(part, answer, time)| {
+                let (answer, status) = match answer {
+                    Ok(answer) => (answer.to_string(), "ok"),
+                    Err(err) => (err.to_string(), "ERROR"),
+                };
+                Row {
+                    day: number,
+                    part: Some(part),
+                    answer,
+                    time: Some(time),
+                    status,
+                }
+            })),
+            Ok(Err(err)) => rows.push(failed(number, err.to_string(), "ERROR")),
+            Err(panic) => rows.push(failed(number, panic_message(panic), "PANIC")),
+        }
+    }
+
+    let elapsed = start.elapsed();
+
+    print!("{}", to_table(&rows));
+
+    let failed = rows.iter().filter(|row| row.status != "ok").count();
+    println!();
+    println!("{} days in {:.3?}, {} failed", DAYS.len(), elapsed, failed);
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn solve_day(day: &Day, parts: &[Part]) -> Result<TimedAnswers, AocError> {
+    let input = default_input(day.number);
+    let lines = load_file(&input)?;
+    (day.solve_timed)(&lines, parts).map_err(|e| e.in_file(&input))
+}
+
+fn failed(day: u32, reason: String, status: &'static str) -> Row {
+    Row {
+        day,
+        part: None,
+        answer: reason,
+        time: None,
+        status,
+    }
+}
+
+/// Lays the rows out in aligned columns, one line each: answers and errors that span lines are
+/// escaped as in the manifest.
+fn to_table(rows: &[Row]) -> String {
+    let answers: Vec<String> = rows.iter().map(|row| escape_answer(&row.answer)).collect();
+    let answer_width = answers
+        .iter()
+        .map(|answer| answer.chars().count())
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!(
+        "{:>3}  part  {:<width$}  {:>10}  status\n",
+        "day",
+        "answer",
+        "time",
+        width = answer_width
+    );
+    for (row, answer) in rows.iter().zip(&answers) {
+        table.push_str(&format!(
+            "{:>3}  {:<4}  {:<width$}  {:>10}  {}\n",
+            row.day,
+            row.part.map_or("-".to_owned(), |part| part.to_string()),
+            answer,
+            row.time
+                .map_or("-".to_owned(), |time| format!("{:.3?}", time)),
+            row.status,
+            width = answer_width
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_table() {
+        let rows = [
+            Row {
+                day: 13,
+                part: Some(Part::B),
+                answer: "#.\n.#".to_owned(),
+                time: Some(Duration::from_millis(2)),
+                status: "ok",
+            },
+            failed(4, "first line\nsecond line".to_owned(), "PANIC"),
+        ];
+
+        assert_eq!(
+            to_table(&rows),
+            "day  part  answer                         time  status
+ 13  B     #.\\n.#                      2.000ms  ok
+  4  -     first line\\nsecond line           -  PANIC
+"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use std::process;
+
+use common::manifest::{escape_answer, load_manifest, Expected};
+use common::Part;
+
+use crate::{default_input, find_day, solve_file, usage, DAYS};
+
+const DEFAULT_MANIFEST: &str = "data/answers.txt";
+
+enum Status {
+    Pass,
+    Fail(String),
+    Error(String),
+    Unrecorded,
+}
+
+struct Row {
+    day: u32,
+    part: Part,
+    input: String,
+    answer: String,
+    status: Status,
+}
+
+/// Re-solves every day against its input and compares the answers with the manifest.
+pub fn verify(args: &[String]) {
+    let manifest = match args {
+        [] => DEFAULT_MANIFEST,
+        [manifest] => manifest.as_str(),
+        _ => usage(),
+    };
+
+    let expected = match load_manifest(manifest) {
+        Ok(expected) => expected,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+
+    let rows = check(&expected);
+    print_table(&rows);
+
+    let failed = rows
+        .iter()
+        .filter(|row| matches!(row.status, Status::Fail(_) | Status::Error(_)))
+        .count();
+    println!();
+    println!("{} checked, {} failed", rows.len(), failed);
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// The parts to check on one input, with the answer recorded for each, if any.
+type Recorded<'a> = Vec<(Part, Option<&'a str>)>;
+
+fn check(expected: &[Expected]) -> Vec<Row> {
+    let mut by_input: BTreeMap<(u32, String), Recorded> = BTreeMap::new();
+    for e in expected {
+        by_input
+            .entry((e.day, e.input.clone()))
+            .or_default()
+            .push((e.part, Some(&e.answer)));
+    }
+    for day in DAYS {
+        for part in [Part::A, Part::B] {
+            if !expected
+                .iter()
+                .any(|e| e.day == day.number && e.part == part)
+            {
+                by_input
+                    .entry((day.number, default_input(day.number)))
+                    .or_default()
+                    .push((part, None));
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+
+    for ((number, input), mut expected) in by_input {
+        expected.sort_by_key(|(part, _)| *part == Part::B);
+
+        let parts: Vec<Part> = expected.iter().map(|(part, _)| *part).collect();
+        let solved = match find_day(number) {
+            Some(day) => solve_file(day, &input, &parts, false).map_err(|e| e.to_string()),
+            None => Err(format!("no solution registered for day {}", number)),
+        };
+
+        for (part, recorded) in expected {
+            let answer = match &solved {
+                Ok(answers) => answers
+                    .iter()
+                    .find(|(p, _)| *p == part)
+                    .map(|(_, answer)| answer.as_ref().map_err(|e| e.to_string()))
+                    .expect("every part asked for is answered"),
+                Err(err) => Err(err.clone()),
+            };
+            let (answer, status) = match answer {
+                Ok(answer) => {
+                    let answer = answer.to_string();
+                    let status = match recorded {
+                        None => Status::Unrecorded,
+                        Some(recorded) if recorded == answer => Status::Pass,
+                        Some(recorded) => Status::Fail(recorded.to_owned()),
+                    };
+                    (answer, status)
+                }
+                Err(err) => (String::new(), Status::Error(err)),
+            };
+
+            rows.push(Row {
+                day: number,
+                part,
+                input: input.clone(),
+                answer,
+                status,
+            });
+        }
+    }
+
+    rows
+}
+
+fn print_table(rows: &[Row]) {
+    let input_width = rows
+        .iter()
+        .map(|row| row.input.len())
+        .chain(["input".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:>3}  part  {:<width$}  {:<10}  answer",
+        "day",
+        "input",
+        "status",
+        width = input_width
+    );
+    for row in rows {
+        let (status, detail) = match &row.status {
+            Status::Pass => ("ok", String::new()),
+            Status::Fail(expected) => ("FAIL", format!(" (expected {})", escape_answer(expected))),
+            Status::Error(err) => ("ERROR", err.clone()),
+            Status::Unrecorded => ("unrecorded", String::new()),
+        };
+        println!(
+            "{:>3}  {:<4}  {:<width$}  {:<10}  {}{}",
+            row.day,
+            row.part,
+            row.input,
+            status,
+            escape_answer(&row.answer),
+            detail,
+            width = input_width
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::fs;
+use std::process;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use common::manifest::escape_answer;
+use common::{Answer, Answers, AocError, Part};
+
+use crate::{default_input, find_day, solve_file, usage};
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+const EXAMPLES: &str = "data/examples";
+
+/// The answers found on one input, or why there were none.
+type Outcome = Result<Answers, String>;
+
+/// Polls a day's inputs, by default its puzzle input and examples, and re-solves each one that
+/// changes, printing how its answers moved since the previous run.
+pub fn watch(args: &[String]) {
+    let mut target = None;
+    let mut parts = vec![Part::A, Part::B];
+    let mut interval = DEFAULT_INTERVAL;
+    let mut files = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                parts = match args.next().map(|s| s.parse::<Part>()) {
+                    Some(Ok(part)) => vec![part],
+                    _ => usage(),
+                }
+            }
+            "--interval" => {
+                interval = match args.next().map(|s| s.parse::<u64>()) {
+                    Some(Ok(ms)) if ms > 0 => Duration::from_millis(ms),
+                    _ => usage(),
+                }
+            }
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ => files.push(arg.clone()),
+        }
+    }
+
+    let day = match target.map(|number| (number, number.parse::<u32>().ok().and_then(find_day))) {
+        Some((_, Some(day))) => day,
+        Some((number, None)) => {
+            println!("No solution registered for day {}", number);
+            process::exit(1);
+        }
+        None => usage(),
+    };
+
+    if files.is_empty() {
+        files.push(default_input(day.number));
+        files.extend(example_inputs(day.number));
+    }
+
+    println!("Watching {} for day {}", files.join(", "), day.number);
+
+    let mut modified: HashMap<&str, Option<SystemTime>> = HashMap::new();
+    let mut outcomes: HashMap<&str, Outcome> = HashMap::new();
+
+    loop {
+        for file in &files {
+            let current = fs::metadata(file).and_then(|m| m.modified()).ok();
+            if modified.get(file.as_str()) == Some(&current) {
+                continue;
+            }
+            modified.insert(file, current);
+
+            let outcome = solve_file(day, file, &parts, false).map_err(|e| e.to_string());
+            println!("== {}", file);
+            for line in describe(outcomes.get(file.as_str()), &outcome) {
+                println!("  {}", line);
+            }
+            outcomes.insert(file, outcome);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+/// The example inputs for a day: `dayN.txt` and any `dayN-<name>.txt`, sorted by name.
+pub fn example_inputs(number: u32) -> Vec<String> {
+    let exact = format!("day{}.txt", number);
+    let prefix = format!("day{}-", number);
+
+    let mut examples: Vec<String> = fs::read_dir(EXAMPLES)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| *name == exact || (name.starts_with(&prefix) && name.ends_with(".txt")))
+        .map(|name| format!("{}/{}", EXAMPLES, name))
+        .collect();
+    examples.sort();
+
+    examples
+}
+
+/// One line per part comparing the new answers with the previous run.
+fn describe(previous: Option<&Outcome>, current: &Outcome) -> Vec<String> {
+    let answers = match current {
+        Ok(answers) => answers,
+        Err(err) => return vec![format!("error: {}", err)],
+    };
+
+    answers
+        .iter()
+        .map(|(part, answer)| {
+            let answer = show(answer);
+            let before = match previous {
+                Some(Ok(before)) => before.iter().find(|(p, _)| p == part).map(|(_, a)| show(a)),
+                _ => None,
+            };
+
+            match (previous, before) {
+                (None, _) => format!("{}: {}", part, answer),
+                (Some(Err(_)), _) => format!("{}: {} (was an error)", part, answer),
+                (_, Some(before)) if before == answer => {
+                    format!("{}: {} (unchanged)", part, answer)
+                }
+                (_, Some(before)) => format!("{}: {} -> {}", part, before, answer),
+                (_, None) => format!("{}: {}", part, answer),
+            }
+        })
+        .collect()
+}
+
+fn show(answer: &Result<Answer, AocError>) -> String {
+    match answer {
+        Ok(answer) => escape_answer(&answer.to_string()),
+        Err(err) => format!("error ({})", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(a: i64, b: &str) -> Outcome {
+        Ok(vec![
+            (Part::A, Ok(Answer::from(a))),
+            (Part::B, Ok(Answer::from(b))),
+        ])
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(None, &answers(17, "O")), vec!["A: 17", "B: O"]);
+        assert_eq!(
+            describe(Some(&answers(17, "O")), &answers(18, "O")),
+            vec!["A: 17 -> 18", "B: O (unchanged)"]
+        );
+        assert_eq!(
+            describe(
+                Some(&Err("day13.txt:3: bad fold".to_owned())),
+                &answers(17, "O")
+            ),
+            vec!["A: 17 (was an error)", "B: O (was an error)"]
+        );
+        assert_eq!(
+            describe(Some(&answers(17, "O")), &Err("empty input".to_owned())),
+            vec!["error: empty input"]
+        );
+
+        let unreadable = Ok(vec![
+            (Part::A, Ok(Answer::from(17))),
+            (Part::B, Err(AocError::new("unknown letter"))),
+        ]);
+        assert_eq!(
+            describe(Some(&answers(17, "O")), &unreadable),
+            vec!["A: 17 (unchanged)", "B: O -> error (unknown letter)"]
+        );
+    }
+
+    #[test]
+    fn test_example_inputs() {
+        assert_eq!(
+            example_inputs(16),
+            vec!["data/examples/day16-b.txt", "data/examples/day16.txt"]
+        );
+        assert_eq!(example_inputs(1), vec!["data/examples/day1.txt"]);
+        assert!(example_inputs(99).is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+use std::env;
+use std::process::{Command, Stdio};
+
+#[test]
+fn stops_after_the_maximum_frames() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["animate", "6", "--delay", "0", "--frames", "5"])
+        .arg("data/examples/day6.txt")
+        .output()
+        .expect("failed to run aoc");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("\x1b[H").count(), 5);
+    assert!(stdout.contains("day 4: 9 fish"));
+    assert!(!stdout.contains("day 5:"));
+}
+
+#[test]
+fn stops_quietly_when_the_reader_goes_away() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["animate", "6", "--delay", "0"])
+        .arg("data/examples/day6.txt")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run aoc");
+    drop(child.stdout.take());
+
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "{}", stderr);
+    assert_eq!(stderr, "");
+}
@@ -0,0 +1,94 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn tabulates_every_input_in_a_directory() {
+    let dir = env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("alice.txt"), "3,4,3,1,2\n").unwrap();
+    fs::write(dir.join("bob.txt"), "1\r\n").unwrap();
+    fs::write(dir.join(".alice.txt.swp"), "not an input").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["batch", "6", "--format", "csv"])
+        .arg(&dir)
+        .output()
+        .expect("failed to run aoc");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<Vec<&str>> = stdout
+        .lines()
+        .map(|line| line.split(',').collect())
+        .collect();
+    let alice = dir.join("alice.txt");
+    let bob = dir.join("bob.txt");
+    assert_eq!(
+        rows,
+        [
+            vec!["day", "part", "input", "answer", "error"],
+            vec!["6", "A", alice.to_str().unwrap(), "5934", ""],
+            vec!["6", "B", alice.to_str().unwrap(), "26984457539", ""],
+            vec!["6", "A", bob.to_str().unwrap(), "1401", ""],
+            vec!["6", "B", bob.to_str().unwrap(), "6206821033", ""],
+        ]
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn falls_back_to_the_puzzle_input_and_examples() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["batch", "6", "--part", "a", "--format", "csv"])
+        .output()
+        .expect("failed to run aoc");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let inputs: Vec<&str> = stdout
+        .lines()
+        .skip(1)
+        .map(|line| line.split(',').nth(2).unwrap())
+        .collect();
+    assert_eq!(inputs, ["data/day6.txt", "data/examples/day6.txt"]);
+}
+
+#[test]
+fn skips_a_day_without_inputs_when_running_all() {
+    let dir = env::temp_dir().join(format!("aoc-batch-all-{}", std::process::id()));
+    fs::create_dir_all(dir.join("data/day2")).unwrap();
+    fs::write(dir.join("data/day1.txt"), "199\n200\n208\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(&dir)
+        .args(["batch", "all", "--part", "a", "--format", "csv"])
+        .output()
+        .expect("failed to run aoc");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout,
+        "day,part,input,answer,error\n1,A,data/day1.txt,2,\n"
+    );
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("skipping day 2"), "{}", stderr);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,19 @@
+use std::env;
+use std::process::Command;
+
+#[test]
+fn alternatives_agree_with_the_main_implementations() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["differential", "all", "--runs", "20", "--size", "10"])
+        .output()
+        .expect("failed to run aoc");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success(), "{}", stdout);
+    assert!(
+        stdout.starts_with("Day 1: part2_clever (part B) agreed on 21 inputs\n"),
+        "{}",
+        stdout
+    );
+}
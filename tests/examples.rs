@@ -0,0 +1,129 @@
+use std::process::{Command, Output};
+
+/// Runs the `aoc` binary on an example from `data/examples` with tsv output.
+fn run_tsv(day: u32, example: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["run", &day.to_string(), "--format", "tsv"])
+        .args(args)
+        .arg(format!("data/examples/{}", example))
+        .output()
+        .expect("failed to run aoc")
+}
+
+/// The fields of each row of tsv output, without the header.
+fn rows(output: &Output) -> Vec<Vec<String>> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .skip(1)
+        .map(|line| line.split('\t').map(|field| field.to_owned()).collect())
+        .collect()
+}
+
+/// Runs the `aoc` binary on an example and returns the answers by part, as written in its tsv
+/// output.
+fn run(day: u32, example: &str, args: &[&str]) -> Vec<(String, String)> {
+    let output = run_tsv(day, example, args);
+
+    assert!(
+        output.status.success(),
+        "day {} failed: {}",
+        day,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    rows(&output)
+        .into_iter()
+        .map(|fields| (fields[1].clone(), fields[4].clone()))
+        .collect()
+}
+
+fn assert_answers(day: u32, example: &str, part_a: &str, part_b: &str) {
+    assert_eq!(
+        run(day, example, &[]),
+        vec![
+            ("A".to_owned(), part_a.to_owned()),
+            ("B".to_owned(), part_b.to_owned())
+        ]
+    );
+}
+
+fn assert_answer(day: u32, example: &str, part: &str, answer: &str) {
+    assert_eq!(
+        run(day, example, &["--part", part]),
+        vec![(part.to_uppercase(), answer.to_owned())]
+    );
+}
+
+#[test]
+fn day1() {
+    assert_answers(1, "day1.txt", "7", "5");
+}
+
+#[test]
+fn day2() {
+    assert_answers(2, "day2.txt", "150", "900");
+}
+
+#[test]
+fn day3() {
+    assert_answers(3, "day3.txt", "198", "230");
+}
+
+#[test]
+fn day4() {
+    assert_answers(4, "day4.txt", "4512", "1924");
+}
+
+#[test]
+fn day5() {
+    assert_answers(5, "day5.txt", "5", "12");
+}
+
+#[test]
+fn day6() {
+    assert_answers(6, "day6.txt", "5934", "26984457539");
+}
+
+#[test]
+fn day7() {
+    assert_answers(7, "day7.txt", "37", "168");
+}
+
+#[test]
+fn day10() {
+    assert_answers(10, "day10.txt", "26397", "288957");
+}
+
+#[test]
+fn day13() {
+    // part b of the example folds into a square, which is not a letter, but part a is still
+    // answered
+    let output = run_tsv(13, "day13.txt", &[]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        rows(&output),
+        vec![
+            vec!["13", "A", "data/examples/day13.txt", "integer", "17"],
+            vec![
+                "13",
+                "B",
+                "data/examples/day13.txt",
+                "error",
+                "day13.txt: expected letters 6 rows tall, found 7 rows"
+            ],
+        ]
+    );
+}
+
+#[test]
+fn day16() {
+    assert_answer(16, "day16.txt", "a", "31");
+    assert_answer(16, "day16-b.txt", "b", "1");
+}
+
+#[test]
+fn day17() {
+    assert_answers(17, "day17.txt", "45", "112");
+}
@@ -0,0 +1,125 @@
+use std::env;
+use std::ffi::{c_char, CStr};
+use std::path::PathBuf;
+use std::process::Command;
+use std::ptr;
+
+// links the library, whose exported symbols the declarations below resolve to
+extern crate common;
+
+const AOC_OK: i32 = 0;
+const AOC_ERROR: i32 = 1;
+const AOC_UNKNOWN_DAY: i32 = 2;
+const AOC_INVALID_ARGUMENT: i32 = 3;
+
+extern "C" {
+    fn aoc_solve(
+        day: u32,
+        part: c_char,
+        input: *const u8,
+        len: usize,
+        answer: *mut *mut c_char,
+    ) -> i32;
+    fn aoc_free(s: *mut c_char);
+}
+
+/// Calls `aoc_solve` the way a C caller would and returns its status and string.
+fn solve(day: u32, part: u8, input: &[u8]) -> (i32, String) {
+    let mut answer: *mut c_char = ptr::null_mut();
+    unsafe {
+        let status = aoc_solve(
+            day,
+            part as c_char,
+            input.as_ptr(),
+            input.len(),
+            &mut answer,
+        );
+        assert!(!answer.is_null());
+        let text = CStr::from_ptr(answer).to_str().unwrap().to_owned();
+        aoc_free(answer);
+        (status, text)
+    }
+}
+
+#[test]
+fn solves_days_through_the_c_interface() {
+    let vents = include_bytes!("../data/examples/day5.txt");
+    assert_eq!(solve(5, b'a', vents), (AOC_OK, "5".to_owned()));
+    assert_eq!(solve(5, b'B', vents), (AOC_OK, "12".to_owned()));
+
+    assert_eq!(
+        solve(16, b'a', b"8A004A801A8002F478\n"),
+        (AOC_OK, "16".to_owned())
+    );
+    assert_eq!(
+        solve(16, b'b', b"9C0141080250320F1802104A08"),
+        (AOC_OK, "1".to_owned())
+    );
+}
+
+#[test]
+fn reports_failures_through_the_c_interface() {
+    let (status, reason) = solve(5, b'a', b"0,9 -> 5,9\n8,0 -> 0,x\n");
+    assert_eq!(status, AOC_ERROR);
+    assert!(reason.starts_with("2:"), "{}", reason);
+
+    assert_eq!(solve(25, b'a', b"").0, AOC_UNKNOWN_DAY);
+    assert_eq!(solve(16, b'c', b"D2FE28").0, AOC_INVALID_ARGUMENT);
+    assert_eq!(solve(16, b'a', b"\xffD2FE28").0, AOC_INVALID_ARGUMENT);
+
+    unsafe {
+        let mut answer: *mut c_char = ptr::null_mut();
+        assert_eq!(
+            aoc_solve(16, b'a' as c_char, ptr::null(), 4, &mut answer),
+            AOC_INVALID_ARGUMENT
+        );
+        aoc_free(answer);
+        assert_eq!(
+            aoc_solve(16, b'a' as c_char, ptr::null(), 0, ptr::null_mut()),
+            AOC_INVALID_ARGUMENT
+        );
+        aoc_free(ptr::null_mut());
+    }
+}
+
+/// Compiles `tests/ffi/solve.c` against the header and the shared library next to `aoc`.
+#[test]
+fn c_program_links_against_the_header() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target = PathBuf::from(env!("CARGO_BIN_EXE_aoc"))
+        .parent()
+        .unwrap()
+        .to_owned();
+    let library = target.join(format!(
+        "{}common{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    ));
+    assert!(library.exists(), "{} was not built", library.display());
+
+    let program = target.join("ffi-solve");
+    let compiled = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_owned()))
+        .arg(root.join("tests/ffi/solve.c"))
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg(&library)
+        .arg(format!("-Wl,-rpath,{}", target.display()))
+        .arg("-o")
+        .arg(&program)
+        .status();
+    match compiled {
+        Ok(status) => assert!(status.success(), "failed to compile tests/ffi/solve.c"),
+        Err(err) => {
+            eprintln!("skipping, no C compiler: {}", err);
+            return;
+        }
+    }
+
+    let output = Command::new(&program)
+        .args(["16", "a", "data/examples/day16.txt"])
+        .current_dir(&root)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "0 31\n");
+}
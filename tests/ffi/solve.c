@@ -0,0 +1,33 @@
+/* Solves a part of a day on a file through the C interface: solve <day> <part> <file> */
+
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "aoc2021.h"
+
+int main(int argc, char **argv) {
+    if (argc != 4) {
+        fprintf(stderr, "usage: solve <day> <part> <file>\n");
+        return 2;
+    }
+
+    FILE *f = fopen(argv[3], "rb");
+    if (f == NULL) {
+        perror(argv[3]);
+        return 2;
+    }
+    fseek(f, 0, SEEK_END);
+    long len = ftell(f);
+    fseek(f, 0, SEEK_SET);
+    uint8_t *input = malloc(len > 0 ? len : 1);
+    size_t read = fread(input, 1, len, f);
+    fclose(f);
+
+    char *answer = NULL;
+    int32_t status = aoc_solve(atoi(argv[1]), argv[2][0], input, read, &answer);
+    free(input);
+
+    printf("%d %s\n", status, answer);
+    aoc_free(answer);
+    return status == AOC_OK ? 0 : 1;
+}
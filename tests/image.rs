@@ -0,0 +1,34 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn writes_a_picture_per_fold() {
+    let out = env::temp_dir().join(format!("aoc-image-{}", std::process::id()));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["image", "13", "--scale", "2", "--out"])
+        .arg(&out)
+        .arg("data/examples/day13.txt")
+        .output()
+        .expect("failed to run aoc");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut written: Vec<String> = fs::read_dir(&out)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    written.sort();
+    assert_eq!(written, ["day13-00.pgm", "day13-01.pgm", "day13-02.pgm"]);
+
+    let folded = fs::read(out.join("day13-02.pgm")).unwrap();
+    assert!(folded.starts_with(b"P5\n10 14\n255\n"));
+    assert_eq!(folded.len(), "P5\n10 14\n255\n".len() + 10 * 14);
+
+    fs::remove_dir_all(&out).unwrap();
+}
@@ -0,0 +1,61 @@
+use common::days::{day16, day5, day6};
+use common::geometry::Point;
+use common::grid::Grid;
+use common::{days, load_str, Part, Solution};
+
+#[test]
+fn day5_layout_is_reusable() {
+    let lines = day5::Day5::parse(&load_str("0,9 -> 5,9\n0,9 -> 2,9\n8,0 -> 0,8")).unwrap();
+
+    let straight = day5::layout_lines(&lines, false);
+    assert_eq!(straight.get(Point::new(1, 9)), Some(&2));
+    assert_eq!(day5::count_dangerous_spots(&straight), 3);
+
+    let all = day5::layout_lines(&lines, true);
+    assert_eq!(day5::count_dangerous_spots(&all), 3);
+    assert_eq!(all.get(Point::new(4, 4)), Some(&1));
+}
+
+#[test]
+fn day6_simulation_is_reusable() {
+    let fish = day6::Day6::parse(&load_str("3,4,3,1,2")).unwrap();
+
+    assert_eq!(day6::simulate(fish.clone(), 18), Some(26));
+    assert_eq!(day6::simulate(fish, 80), Some(5934));
+}
+
+#[test]
+fn day16_packets_are_reusable() {
+    let packet = day16::Day16::parse(&load_str("9C0141080250320F1802104A08")).unwrap();
+
+    assert!(matches!(
+        packet.content,
+        day16::PacketContent::Operator { ref subpackets } if subpackets.len() == 2
+    ));
+    assert_eq!(day16::evaluate(&packet), Ok(1));
+    assert_eq!(day16::sum_packet_versions(&packet), 20);
+}
+
+#[test]
+fn copied_examples_solve_the_same() {
+    for day in days::DAYS {
+        let (number, solver) = (day.number, day.solve);
+        let path = format!(
+            "{}/data/examples/day{}.txt",
+            env!("CARGO_MANIFEST_DIR"),
+            number
+        );
+        let example = std::fs::read_to_string(path).unwrap();
+        let copied = format!(
+            "\u{feff}{}\r\n\r\n",
+            example.trim_end().replace('\n', " \r\n")
+        );
+
+        assert_eq!(
+            solver(&load_str(&copied), &[Part::A, Part::B]),
+            solver(&load_str(&example), &[Part::A, Part::B]),
+            "day {}",
+            number
+        );
+    }
+}
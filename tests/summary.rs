@@ -0,0 +1,28 @@
+use std::process::Command;
+
+#[test]
+fn summary_lists_every_day_in_order() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["summary", "--part", "a"])
+        .output()
+        .expect("failed to run aoc");
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "summary failed:\n{}", stdout);
+
+    let rows: Vec<Vec<&str>> = stdout
+        .lines()
+        .skip(1)
+        .take_while(|line| !line.is_empty())
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+
+    let days: Vec<&str> = rows.iter().map(|row| row[0]).collect();
+    assert_eq!(
+        days,
+        ["1", "2", "3", "4", "5", "6", "7", "10", "13", "16", "17"]
+    );
+    assert!(rows.iter().all(|row| row[1] == "A" && row[4] == "ok"));
+    assert_eq!(rows[0][2], "1393");
+}